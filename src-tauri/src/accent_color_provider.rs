@@ -7,6 +7,9 @@ use tokio::time;
 
 use crate::events_manager::EventsManager;
 
+#[cfg(not(windows))]
+const DEFAULT_ACCENT_COLOR: &str = "#0078d4";
+
 #[derive(Clone)]
 pub struct AccentColorProvider {
     app: AppHandle,
@@ -31,6 +34,7 @@ impl AccentColorProvider {
         self.accent_color.lock().unwrap().clone()
     }
 
+    #[cfg(windows)]
     pub fn fetch_accent_color() -> String {
         let mut colorization: u32 = 0;
         let mut opaqueblend = windows::Win32::Foundation::BOOL(0);
@@ -49,6 +53,12 @@ impl AccentColorProvider {
         return format!("#{}", rgb_string).to_string();
     }
 
+    // There is no common way to get accent color on other platforms, so default Windows one is used
+    #[cfg(not(windows))]
+    pub fn fetch_accent_color() -> String {
+        DEFAULT_ACCENT_COLOR.to_string()
+    }
+
     fn spawn_watch_task(&self) {
         let self_clone = self.clone();
         let app_clone = self.app.clone();
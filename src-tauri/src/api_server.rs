@@ -0,0 +1,218 @@
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    extract::{Request, State},
+    http::{header::AUTHORIZATION, StatusCode},
+    middleware::{self, Next},
+    response::Response,
+    routing::post,
+    Json, Router,
+};
+use log::{info, warn};
+use serde::Deserialize;
+use subtle::ConstantTimeEq;
+use tauri::{async_runtime::JoinHandle, AppHandle, Manager};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    events_manager::EventsManager,
+    notifications::{show_error_notification, show_notification},
+    settings::{ApiServerSettings, SettingsManager},
+    translation_providers::{TranslateRequest, TranslateResult},
+    translator, window_manager,
+};
+
+#[derive(Clone)]
+struct ApiState {
+    app: AppHandle,
+    auth_token: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TranslateTextRequest {
+    text: String,
+    source_language: Option<String>,
+    target_language: Option<String>,
+    #[serde(default)]
+    is_forced_translation: bool,
+}
+
+#[derive(Deserialize)]
+struct PlayTextRequest {
+    text: String,
+}
+
+// Local HTTP API, so external tools (editor plugins, scripts) can translate through the running app
+// and reuse its proxy, cache and language settings
+// Server listens on localhost only and requires auth token for every request
+#[derive(Clone)]
+pub struct ApiServer {
+    app: AppHandle,
+    shutdown_token: Arc<Mutex<Option<CancellationToken>>>,
+    server_task: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl ApiServer {
+    pub fn new(app: &AppHandle) -> Self {
+        let api_server = Self {
+            app: app.clone(),
+            shutdown_token: Arc::new(Mutex::new(None)),
+            server_task: Arc::new(Mutex::new(None)),
+        };
+
+        let settings_manager = app.state::<SettingsManager>();
+        api_server.start(&settings_manager.read_settings().api_server);
+
+        let self_clone = api_server.clone();
+        settings_manager.add_change_handler(move |old_settings, new_settings| {
+            if old_settings.api_server != new_settings.api_server {
+                self_clone.stop();
+                self_clone.start(&new_settings.api_server);
+            }
+        });
+
+        api_server
+    }
+
+    fn start(&self, settings: &ApiServerSettings) {
+        if !settings.enabled {
+            return;
+        }
+
+        if settings.auth_token.is_empty() {
+            warn!("API server is enabled, but auth token is not set. Server won't be started.");
+            show_notification(
+                &self.app,
+                "API server is not started",
+                Some("Auth token must be set in settings."),
+            );
+            return;
+        }
+
+        let shutdown_token = CancellationToken::new();
+        *self.shutdown_token.lock().unwrap() = Some(shutdown_token.clone());
+
+        let state = ApiState {
+            app: self.app.clone(),
+            auth_token: settings.auth_token.clone(),
+        };
+        let port = settings.port;
+        let app = self.app.clone();
+        let previous_server_task = self.server_task.lock().unwrap().take();
+
+        let server_task = tauri::async_runtime::spawn(async move {
+            // Previous server must release the port before the new one is started
+            if let Some(previous_server_task) = previous_server_task {
+                previous_server_task.await.ok();
+            }
+
+            if let Err(err) = Self::serve(state, port, shutdown_token).await {
+                show_error_notification(&app, Box::new(err), "Error running API server.");
+            }
+        });
+        *self.server_task.lock().unwrap() = Some(server_task);
+    }
+
+    fn stop(&self) {
+        if let Some(shutdown_token) = self.shutdown_token.lock().unwrap().take() {
+            info!("Stopping API server.");
+            shutdown_token.cancel();
+        }
+    }
+
+    async fn serve(
+        state: ApiState,
+        port: u16,
+        shutdown_token: CancellationToken,
+    ) -> Result<(), std::io::Error> {
+        let router = Router::new()
+            .route("/translate", post(Self::translate))
+            .route("/play", post(Self::play))
+            .route("/history", post(Self::show_history))
+            .route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                Self::authorize,
+            ))
+            .with_state(state);
+
+        let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+        let listener = tokio::net::TcpListener::bind(address).await?;
+        info!("API server is listening on {address}.");
+
+        axum::serve(listener, router)
+            .with_graceful_shutdown(shutdown_token.cancelled_owned())
+            .await
+    }
+
+    async fn authorize(
+        State(state): State<ApiState>,
+        request: Request,
+        next: Next,
+    ) -> Result<Response, StatusCode> {
+        let expected_header = format!("Bearer {}", state.auth_token);
+        let is_authorized = request
+            .headers()
+            .get(AUTHORIZATION)
+            // Constant-time comparison, so the token can't be guessed by measuring response time
+            .is_some_and(|header| bool::from(header.as_bytes().ct_eq(expected_header.as_bytes())));
+
+        if !is_authorized {
+            warn!("Rejected unauthorized API request to {}.", request.uri());
+            return Err(StatusCode::UNAUTHORIZED);
+        }
+
+        Ok(next.run(request).await)
+    }
+
+    async fn translate(
+        State(state): State<ApiState>,
+        Json(request): Json<TranslateTextRequest>,
+    ) -> Result<Json<TranslateResult>, (StatusCode, String)> {
+        let settings = state
+            .app
+            .state::<SettingsManager>()
+            .read_settings()
+            .translation;
+
+        let default_request = TranslateRequest::from_settings(&settings, request.text);
+        let translate_request = TranslateRequest {
+            source_language: request
+                .source_language
+                .unwrap_or(default_request.source_language),
+            target_language: request
+                .target_language
+                .unwrap_or(default_request.target_language),
+            is_forced_translation: request.is_forced_translation,
+            ..default_request
+        };
+
+        translator::translate(&state.app, &translate_request)
+            .await
+            .map(Json)
+            .map_err(|err| (StatusCode::BAD_GATEWAY, err.to_string()))
+    }
+
+    // Text is played by the translation window, same as for the play hotkey
+    async fn play(
+        State(state): State<ApiState>,
+        Json(request): Json<PlayTextRequest>,
+    ) -> StatusCode {
+        let (window, _) = window_manager::get_or_create_translation_window(&state.app, true);
+        state
+            .app
+            .state::<EventsManager>()
+            .emit_play_given_text_command(&window, Some(request.text));
+
+        StatusCode::NO_CONTENT
+    }
+
+    async fn show_history(State(state): State<ApiState>) -> StatusCode {
+        window_manager::show_history_window(&state.app);
+
+        StatusCode::NO_CONTENT
+    }
+}
@@ -0,0 +1,66 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use log::warn;
+use tauri::AppHandle;
+
+use crate::notifications::show_notification;
+
+// Stops sending requests to a host for a cooldown period after it starts rejecting them (rate limits, captcha)
+// Without it, every hotkey press would fail with the same error, making things only worse
+pub struct CircuitBreaker {
+    app: AppHandle,
+    open_until: Mutex<HashMap<String, Instant>>,
+}
+
+impl CircuitBreaker {
+    pub fn new(app: &AppHandle) -> Self {
+        Self {
+            app: app.clone(),
+            open_until: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Returns remaining cooldown if requests to the host are currently blocked
+    pub fn remaining_cooldown(&self, host: &str) -> Option<Duration> {
+        let mut open_until = self.open_until.lock().unwrap();
+        let host_open_until = *open_until.get(host)?;
+
+        let now = Instant::now();
+        if host_open_until <= now {
+            open_until.remove(host);
+            return None;
+        }
+
+        Some(host_open_until - now)
+    }
+
+    pub fn open(&self, host: &str, cooldown: Duration) {
+        let mut open_until = self.open_until.lock().unwrap();
+        let is_already_open = open_until
+            .get(host)
+            .is_some_and(|host_open_until| *host_open_until > Instant::now());
+
+        open_until.insert(host.to_string(), Instant::now() + cooldown);
+        drop(open_until);
+
+        // User is notified only once per cooldown period
+        if !is_already_open {
+            warn!(
+                "Requests to {host} are paused for {} seconds.",
+                cooldown.as_secs()
+            );
+            show_notification(
+                &self.app,
+                "Translation service is rejecting requests",
+                Some(format!(
+                    "Requests are paused for {} minute(s).",
+                    cooldown.as_secs().div_ceil(60)
+                )),
+            );
+        }
+    }
+}
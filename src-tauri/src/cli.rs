@@ -0,0 +1,200 @@
+use std::fmt::Display;
+
+use log::{info, warn};
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    deep_link,
+    events_manager::{EventsManager, TranslateTextCommandData},
+    notifications::show_error_notification,
+    shortcuts_manager::ShortcutsManager,
+    tray_icon::AppTrayIcon,
+    window_manager,
+};
+
+const TRANSLATE_ARG: &str = "--translate";
+const TRANSLATE_CLIPBOARD_ARG: &str = "--translate-clipboard";
+const SHOW_HISTORY_ARG: &str = "--show-history";
+const SHOW_SETTINGS_ARG: &str = "--show-settings";
+const SUSPEND_ARG: &str = "--suspend";
+const RESUME_ARG: &str = "--resume";
+
+#[derive(Debug, PartialEq)]
+pub enum CliCommand {
+    Translate(String),
+    TranslateClipboard,
+    ShowHistory,
+    ShowSettings,
+    Suspend,
+    Resume,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CliError {
+    MissingValue(&'static str),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::MissingValue(arg) => write!(f, "Value is missing for {arg} argument"),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+// Parses command line arguments, first argument is expected to be the executable path
+// Both `--translate text` and `--translate=text` forms are supported
+pub fn parse_args(args: &[String]) -> Result<Vec<CliCommand>, CliError> {
+    let mut commands = Vec::new();
+    let mut args_iter = args.iter().skip(1).peekable();
+
+    while let Some(arg) = args_iter.next() {
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+
+        match name {
+            TRANSLATE_ARG => {
+                // Flag in place of the value means it was forgotten, inline form can be used for text starting with dashes
+                let text = inline_value
+                    .or_else(|| args_iter.next_if(|next| !next.starts_with("--")).cloned())
+                    .ok_or(CliError::MissingValue(TRANSLATE_ARG))?;
+                commands.push(CliCommand::Translate(text));
+            }
+            TRANSLATE_CLIPBOARD_ARG => commands.push(CliCommand::TranslateClipboard),
+            SHOW_HISTORY_ARG => commands.push(CliCommand::ShowHistory),
+            SHOW_SETTINGS_ARG => commands.push(CliCommand::ShowSettings),
+            SUSPEND_ARG => commands.push(CliCommand::Suspend),
+            RESUME_ARG => commands.push(CliCommand::Resume),
+            // Deep links are passed as arguments too, but they are handled by the deep link plugin
+            _ if deep_link::is_deep_link(arg) => {}
+            _ => warn!("Unknown command line argument: {arg}"),
+        }
+    }
+
+    Ok(commands)
+}
+
+pub fn execute_args(app: &AppHandle, args: &[String]) {
+    match parse_args(args) {
+        Ok(commands) => execute_commands(app, commands),
+        Err(err) => show_error_notification(app, Box::new(err), "Invalid command line arguments."),
+    }
+}
+
+fn execute_commands(app: &AppHandle, commands: Vec<CliCommand>) {
+    for command in commands {
+        info!("Executing command line command {:?}.", command);
+        execute_command(app, command);
+    }
+}
+
+fn execute_command(app: &AppHandle, command: CliCommand) {
+    match command {
+        CliCommand::Translate(text) => {
+            let window = window_manager::show_translation_window(app);
+            app.state::<EventsManager>()
+                .emit_translate_given_text_command(
+                    &window,
+                    TranslateTextCommandData {
+                        text: Some(text),
+                        ..Default::default()
+                    },
+                );
+        }
+        CliCommand::TranslateClipboard => AppTrayIcon::translate_from_clipboard(app),
+        CliCommand::ShowHistory => {
+            window_manager::show_history_window(app);
+        }
+        CliCommand::ShowSettings => {
+            window_manager::show_settings_window(app);
+        }
+        CliCommand::Suspend => app.state::<ShortcutsManager>().suspend(),
+        CliCommand::Resume => app.state::<ShortcutsManager>().enable(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_args, CliCommand, CliError};
+
+    fn parse(args: &[&str]) -> Result<Vec<CliCommand>, CliError> {
+        let args: Vec<String> = std::iter::once("app.exe")
+            .chain(args.iter().copied())
+            .map(|arg| arg.to_string())
+            .collect();
+        parse_args(&args)
+    }
+
+    #[test]
+    fn parses_flags() {
+        assert_eq!(
+            parse(&[
+                "--translate-clipboard",
+                "--show-history",
+                "--show-settings",
+                "--suspend",
+                "--resume"
+            ]),
+            Ok(vec![
+                CliCommand::TranslateClipboard,
+                CliCommand::ShowHistory,
+                CliCommand::ShowSettings,
+                CliCommand::Suspend,
+                CliCommand::Resume
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_translate_text_in_both_forms() {
+        assert_eq!(
+            parse(&["--translate", "hello world", "--translate=a=b"]),
+            Ok(vec![
+                CliCommand::Translate("hello world".to_string()),
+                CliCommand::Translate("a=b".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn keeps_inline_translate_text_starting_with_dashes() {
+        assert_eq!(
+            parse(&["--translate=--help"]),
+            Ok(vec![CliCommand::Translate("--help".to_string())])
+        );
+    }
+
+    #[test]
+    fn rejects_translate_without_text() {
+        assert_eq!(
+            parse(&["--translate"]),
+            Err(CliError::MissingValue("--translate"))
+        );
+        assert_eq!(
+            parse(&["--translate", "--show-history"]),
+            Err(CliError::MissingValue("--translate"))
+        );
+    }
+
+    #[test]
+    fn skips_executable_path() {
+        assert_eq!(parse(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn rejects_unknown_arguments() {
+        assert_eq!(
+            parse(&["--unknown", "text", "-s", "--show-history"]),
+            Ok(vec![CliCommand::ShowHistory])
+        );
+    }
+
+    #[test]
+    fn ignores_deep_links() {
+        assert_eq!(parse(&["stt://translate?text=hello"]), Ok(vec![]));
+    }
+}
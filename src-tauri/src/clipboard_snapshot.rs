@@ -0,0 +1,148 @@
+use std::{error::Error, ffi::c_void, ptr};
+
+use log::warn;
+use windows::Win32::{
+    Foundation::{GlobalFree, HANDLE, HGLOBAL, HWND},
+    System::{
+        DataExchange::{
+            CloseClipboard, EmptyClipboard, EnumClipboardFormats, GetClipboardData, OpenClipboard,
+            SetClipboardData,
+        },
+        Memory::{GlobalAlloc, GlobalLock, GlobalSize, GlobalUnlock, GMEM_MOVEABLE},
+        Ole::{
+            CF_BITMAP, CF_DSPBITMAP, CF_DSPENHMETAFILE, CF_DSPMETAFILEPICT, CF_ENHMETAFILE,
+            CF_GDIOBJFIRST, CF_GDIOBJLAST, CF_METAFILEPICT, CF_OWNERDISPLAY, CF_PALETTE,
+            CF_PRIVATEFIRST, CF_PRIVATELAST,
+        },
+    },
+};
+
+// Copy of all clipboard formats, which can be put back after synthetic copy overwrote clipboard
+// Text, HTML, images (as DIB) and files (as HDROP) are stored in global memory, so they can be copied as bytes
+pub struct ClipboardSnapshot {
+    formats: Vec<(u32, Vec<u8>)>,
+}
+
+impl ClipboardSnapshot {
+    pub fn take() -> Result<Self, Box<dyn Error>> {
+        let _clipboard = OpenedClipboard::open()?;
+
+        let mut formats = Vec::new();
+        let mut format = 0;
+        loop {
+            format = unsafe { EnumClipboardFormats(format) };
+            if format == 0 {
+                break;
+            }
+
+            if Self::is_global_memory_format(format) {
+                if let Some(data) = Self::read_format(format) {
+                    formats.push((format, data));
+                }
+            }
+        }
+
+        Ok(Self { formats })
+    }
+
+    pub fn restore(&self) -> Result<(), Box<dyn Error>> {
+        let _clipboard = OpenedClipboard::open()?;
+        unsafe { EmptyClipboard()? };
+
+        // Failed format is skipped, so the rest of the clipboard is still restored
+        for (format, data) in &self.formats {
+            Self::write_format(*format, data).unwrap_or_else(|err| {
+                warn!("Unable to restore clipboard format {}. {}", format, err)
+            });
+        }
+
+        Ok(())
+    }
+
+    // Bitmaps, metafiles and palettes are GDI handles rather than global memory
+    // Private formats hold handles owned by the source app, so their values are meaningless once clipboard is emptied
+    // Images are still preserved, since system provides them as DIB format as well
+    fn is_global_memory_format(format: u32) -> bool {
+        let is_handle_range = [
+            (CF_GDIOBJFIRST, CF_GDIOBJLAST),
+            (CF_PRIVATEFIRST, CF_PRIVATELAST),
+        ]
+        .iter()
+        .any(|(first, last)| (first.0 as u32..=last.0 as u32).contains(&format));
+
+        !is_handle_range
+            && ![
+                CF_BITMAP,
+                CF_ENHMETAFILE,
+                CF_METAFILEPICT,
+                CF_PALETTE,
+                CF_OWNERDISPLAY,
+                CF_DSPBITMAP,
+                CF_DSPENHMETAFILE,
+                CF_DSPMETAFILEPICT,
+            ]
+            .iter()
+            .any(|excluded_format| excluded_format.0 as u32 == format)
+    }
+
+    fn read_format(format: u32) -> Option<Vec<u8>> {
+        unsafe {
+            let handle = GetClipboardData(format).ok()?;
+            let memory = HGLOBAL(handle.0 as *mut c_void);
+
+            // Empty data can't be locked, so such formats are skipped
+            let size = GlobalSize(memory);
+            if size == 0 {
+                return None;
+            }
+
+            let memory_ptr = GlobalLock(memory);
+            if memory_ptr.is_null() {
+                return None;
+            }
+
+            let data = std::slice::from_raw_parts(memory_ptr as *const u8, size).to_vec();
+            GlobalUnlock(memory).ok();
+
+            Some(data)
+        }
+    }
+
+    fn write_format(format: u32, data: &[u8]) -> Result<(), Box<dyn Error>> {
+        unsafe {
+            let memory = GlobalAlloc(GMEM_MOVEABLE, data.len())?;
+            let memory_ptr = GlobalLock(memory);
+            if memory_ptr.is_null() {
+                GlobalFree(memory).ok();
+                return Err("Unable to lock clipboard memory".into());
+            }
+
+            ptr::copy_nonoverlapping(data.as_ptr(), memory_ptr as *mut u8, data.len());
+            GlobalUnlock(memory).ok();
+
+            // Clipboard takes ownership of the memory only when data is set successfully
+            if let Err(err) = SetClipboardData(format, HANDLE(memory.0 as isize)) {
+                GlobalFree(memory).ok();
+                return Err(err.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Closes clipboard once dropped, so it isn't left open on errors
+struct OpenedClipboard;
+
+impl OpenedClipboard {
+    fn open() -> Result<Self, Box<dyn Error>> {
+        unsafe { OpenClipboard(HWND::default())? };
+        Ok(Self)
+    }
+}
+
+impl Drop for OpenedClipboard {
+    fn drop(&mut self) {
+        unsafe { CloseClipboard().ok() };
+    }
+}
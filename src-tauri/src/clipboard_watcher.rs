@@ -0,0 +1,269 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use log::info;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tokio_util::sync::CancellationToken;
+use url::Url;
+
+use crate::{
+    settings::{ClipboardWatcherSettings, SettingsManager},
+    tray_icon::AppTrayIcon,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+type ChangeHandler = Box<dyn Fn(bool) + Send + Sync + 'static>;
+
+// Translates every new text copied to clipboard, so there is no need to press hotkey for each copy
+// Watching state isn't persisted, same as suspended state of hotkeys
+#[derive(Clone)]
+pub struct ClipboardWatcher {
+    app: AppHandle,
+    settings: Arc<Mutex<ClipboardWatcherSettings>>,
+    watch_token: Arc<Mutex<Option<CancellationToken>>>,
+    watching_change_handlers: Arc<Mutex<Vec<ChangeHandler>>>,
+    own_writes_count: Arc<AtomicUsize>,
+    is_resync_needed: Arc<AtomicBool>,
+}
+
+// Clipboard changes are ignored until the guard is dropped
+pub struct OwnClipboardWrite {
+    own_writes_count: Arc<AtomicUsize>,
+    is_resync_needed: Arc<AtomicBool>,
+}
+
+impl ClipboardWatcher {
+    pub fn new(app: &AppHandle) -> Self {
+        let settings_manager = app.state::<SettingsManager>();
+
+        let clipboard_watcher = Self {
+            app: app.clone(),
+            settings: Arc::new(Mutex::new(
+                settings_manager.read_settings().clipboard_watcher,
+            )),
+            watch_token: Arc::new(Mutex::new(None)),
+            watching_change_handlers: Arc::new(Mutex::new(Vec::new())),
+            own_writes_count: Arc::new(AtomicUsize::new(0)),
+            is_resync_needed: Arc::new(AtomicBool::new(false)),
+        };
+
+        let self_clone = clipboard_watcher.clone();
+        settings_manager.add_change_handler(move |_, new_settings| {
+            *self_clone.settings.lock().unwrap() = new_settings.clipboard_watcher.clone();
+        });
+
+        clipboard_watcher
+    }
+
+    pub fn is_watching(&self) -> bool {
+        self.watch_token.lock().unwrap().is_some()
+    }
+
+    pub fn start(&self) {
+        let mut watch_token = self.watch_token.lock().unwrap();
+        if watch_token.is_some() {
+            return;
+        }
+
+        info!("Starting clipboard watch.");
+        let token = CancellationToken::new();
+        *watch_token = Some(token.clone());
+        drop(watch_token);
+
+        let self_clone = self.clone();
+        tauri::async_runtime::spawn(async move {
+            self_clone.watch(token).await;
+        });
+
+        self.call_watching_change_handlers(true);
+    }
+
+    pub fn stop(&self) {
+        let Some(token) = self.watch_token.lock().unwrap().take() else {
+            return;
+        };
+
+        info!("Stopping clipboard watch.");
+        token.cancel();
+
+        self.call_watching_change_handlers(false);
+    }
+
+    pub fn toggle(&self) {
+        if self.is_watching() {
+            self.stop();
+        } else {
+            self.start();
+        }
+    }
+
+    pub fn add_watching_change_handler<F>(&self, handler: F)
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.watching_change_handlers
+            .lock()
+            .unwrap()
+            .push(Box::new(handler));
+    }
+
+    // App writes to clipboard itself (synthetic copy, copied translation, restored snapshot),
+    // such writes must not be translated as if user copied the text
+    pub fn begin_own_write(&self) -> OwnClipboardWrite {
+        self.own_writes_count.fetch_add(1, Ordering::SeqCst);
+
+        OwnClipboardWrite {
+            own_writes_count: self.own_writes_count.clone(),
+            is_resync_needed: self.is_resync_needed.clone(),
+        }
+    }
+
+    fn is_own_write_in_progress(&self) -> bool {
+        // Write might finish between polls, so its result is taken as the last text on the next poll
+        let is_resync_needed = self.is_resync_needed.swap(false, Ordering::SeqCst);
+        is_resync_needed || self.own_writes_count.load(Ordering::SeqCst) > 0
+    }
+
+    fn call_watching_change_handlers(&self, is_watching: bool) {
+        for change_handler in self.watching_change_handlers.lock().unwrap().iter() {
+            change_handler(is_watching);
+        }
+    }
+
+    async fn watch(&self, token: CancellationToken) {
+        // Text which is already in clipboard once watch is started isn't translated
+        let mut last_text = Self::read_clipboard_text(&self.app);
+        let mut changed_at: Option<Instant> = None;
+
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => return,
+                _ = tokio::time::sleep(POLL_INTERVAL) => {}
+            }
+
+            let text = Self::read_clipboard_text(&self.app);
+            if self.is_own_write_in_progress() {
+                last_text = text;
+                changed_at = None;
+                continue;
+            }
+
+            if text != last_text {
+                last_text = text;
+                changed_at = Some(Instant::now());
+                continue;
+            }
+
+            // Text is translated only once clipboard stays unchanged for the debounce interval,
+            // so a series of quick copies results in a single translation
+            let settings = self.settings.lock().unwrap().clone();
+            let debounce = Duration::from_millis(settings.debounce_milliseconds);
+            if changed_at.is_some_and(|changed_at| changed_at.elapsed() >= debounce) {
+                changed_at = None;
+
+                if Self::should_translate(&last_text, &settings) {
+                    AppTrayIcon::translate_from_clipboard(&self.app);
+                }
+            }
+        }
+    }
+
+    fn read_clipboard_text(app: &AppHandle) -> String {
+        // Non-text content can't be read as text, so it's treated as empty
+        app.clipboard().read_text().unwrap_or_default()
+    }
+
+    fn should_translate(text: &str, settings: &ClipboardWatcherSettings) -> bool {
+        let text = text.trim();
+        let length = text.chars().count() as u32;
+
+        if length == 0 || length < settings.min_length || length > settings.max_length {
+            return false;
+        }
+
+        !(settings.ignore_urls && Self::is_url(text))
+    }
+
+    fn is_url(text: &str) -> bool {
+        Url::parse(text).is_ok_and(|url| matches!(url.scheme(), "http" | "https" | "ftp" | "file"))
+    }
+}
+
+impl Drop for OwnClipboardWrite {
+    fn drop(&mut self) {
+        self.is_resync_needed.store(true, Ordering::SeqCst);
+        self.own_writes_count.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> ClipboardWatcherSettings {
+        ClipboardWatcherSettings {
+            min_length: 2,
+            max_length: 10,
+            ignore_urls: true,
+            debounce_milliseconds: 500,
+        }
+    }
+
+    #[test]
+    fn translates_text_within_length_limits() {
+        assert!(ClipboardWatcher::should_translate("hello", &settings()));
+        assert!(ClipboardWatcher::should_translate("  hi  ", &settings()));
+    }
+
+    #[test]
+    fn skips_empty_text() {
+        assert!(!ClipboardWatcher::should_translate("", &settings()));
+        assert!(!ClipboardWatcher::should_translate(" \n\t", &settings()));
+    }
+
+    #[test]
+    fn skips_text_outside_length_limits() {
+        assert!(!ClipboardWatcher::should_translate("a", &settings()));
+        assert!(!ClipboardWatcher::should_translate(
+            "hello world",
+            &settings()
+        ));
+    }
+
+    #[test]
+    fn counts_length_in_characters() {
+        assert!(ClipboardWatcher::should_translate("привет", &settings()));
+    }
+
+    #[test]
+    fn skips_urls_when_ignored() {
+        let mut settings = settings();
+        settings.max_length = 100;
+
+        assert!(!ClipboardWatcher::should_translate(
+            "https://example.com",
+            &settings
+        ));
+        assert!(!ClipboardWatcher::should_translate(
+            "file:///tmp/a.txt",
+            &settings
+        ));
+        assert!(ClipboardWatcher::should_translate(
+            "mailto:a@b.com",
+            &settings
+        ));
+
+        settings.ignore_urls = false;
+        assert!(ClipboardWatcher::should_translate(
+            "https://example.com",
+            &settings
+        ));
+    }
+}
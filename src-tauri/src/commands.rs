@@ -1,13 +1,48 @@
+use std::error::Error;
+
+use log::info;
+use serde::Serialize;
 use tauri::AppHandle;
 
 use crate::{
     accent_color_provider::AccentColorProvider,
     events_manager::{EventsManager, TranslationCommands},
-    notifications, requests_executor,
-    settings::{PartialSettings, Settings, SettingsManager},
+    notifications,
+    requests_executor::{PendingRequestKind, RequestError, RequestsExecutor},
+    settings::{PartialSettings, ProxySettings, Settings, SettingsManager},
     text_extractor::TextExtractor,
+    translation_providers::{TranslateRequest, TranslateResult},
+    translator,
 };
 
+// Kind allows the frontend to tell cancelled requests from failed ones without matching messages
+#[derive(Serialize)]
+pub struct RequestCommandError {
+    kind: &'static str,
+    message: String,
+}
+
+impl From<RequestError> for RequestCommandError {
+    fn from(err: RequestError) -> Self {
+        Self {
+            kind: err.kind(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<Box<dyn Error>> for RequestCommandError {
+    fn from(err: Box<dyn Error>) -> Self {
+        Self {
+            kind: err
+                .downcast_ref::<RequestError>()
+                .map(RequestError::kind)
+                .unwrap_or("other"),
+            message: err.to_string(),
+        }
+    }
+}
+
 #[tauri::command]
 pub fn accent_color(accent_color_provider: tauri::State<AccentColorProvider>) -> String {
     accent_color_provider.accent_color()
@@ -26,17 +61,61 @@ pub fn last_translation_command(
     last_command.clone()
 }
 
+#[tauri::command]
+pub fn take_pending_history_search(events_manager: tauri::State<EventsManager>) -> Option<String> {
+    events_manager.pending_history_search.lock().unwrap().take()
+}
+
 #[tauri::command]
 pub async fn execute_google_translate_request(
-    app: AppHandle,
+    requests_executor: tauri::State<'_, RequestsExecutor>,
+    request_id: String,
     url: String,
     body: String,
     user_agent: String,
-) -> Result<String, String> {
-    let result =
-        requests_executor::execute_google_translate_request(&app, url, body, user_agent).await;
+) -> Result<String, RequestCommandError> {
+    let result = requests_executor
+        .execute_cancellable(
+            request_id,
+            PendingRequestKind::Other,
+            requests_executor.execute_google_translate_request(url, body, user_agent),
+        )
+        .await;
+
+    result.map_err(RequestCommandError::from)
+}
+
+#[tauri::command]
+pub async fn translate_result(
+    app: AppHandle,
+    requests_executor: tauri::State<'_, RequestsExecutor>,
+    request_id: String,
+    request: TranslateRequest,
+) -> Result<TranslateResult, RequestCommandError> {
+    let result = requests_executor
+        .execute_cancellable(
+            request_id,
+            PendingRequestKind::Translation,
+            translator::translate(&app, &request),
+        )
+        .await;
+
+    result.map_err(RequestCommandError::from)
+}
+
+#[tauri::command]
+pub fn cancel_request(requests_executor: tauri::State<RequestsExecutor>, request_id: String) {
+    requests_executor.cancel_request(&request_id);
+}
+
+#[tauri::command]
+pub async fn test_proxy(app: AppHandle, proxy: ProxySettings) -> Result<String, String> {
+    info!("Testing proxy settings: {}:{}", proxy.host, proxy.port);
+    let result = translator::test_proxy(&app, &proxy).await;
 
-    result.map_err(|err| err.to_string())
+    result
+        .map(|result| result.sentence.translation.unwrap_or_default())
+        .map_err(|err| err.to_string())
 }
 
 #[tauri::command]
@@ -51,7 +130,7 @@ pub fn default_settings() -> Settings {
 
 #[tauri::command]
 pub fn reset_settings_to_default(settings_manager: tauri::State<SettingsManager>) {
-    log::info!("Resetting settings to default.");
+    info!("Resetting settings to default.");
     settings_manager.reset_to_default();
 }
 
@@ -65,7 +144,7 @@ pub fn update_settings(
     settings_manager: tauri::State<SettingsManager>,
     updated_settings: PartialSettings,
 ) {
-    log::info!(
+    info!(
         "Received settings from the frontend. Updating settings: {:#?}",
         updated_settings
     );
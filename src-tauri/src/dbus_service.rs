@@ -0,0 +1,133 @@
+use log::{info, warn};
+use tauri::{AppHandle, Manager};
+use zbus::{connection, interface, Connection, SignalContext};
+
+use crate::{
+    events_manager::{EventsManager, TranslateTextCommandData},
+    settings::SettingsManager,
+    shortcuts_manager::ShortcutsManager,
+    tray_icon::AppTrayIcon,
+    window_manager,
+};
+
+const SERVICE_NAME: &str = "org.selectedtexttranslate";
+const OBJECT_PATH: &str = "/org/selectedtexttranslate";
+
+// Exposes tray actions over session bus, so they can be bound to keys in compositors,
+// where global shortcuts don't work reliably (e.g. Wayland)
+struct DbusInterface {
+    app: AppHandle,
+}
+
+#[interface(name = "org.selectedtexttranslate")]
+impl DbusInterface {
+    fn translate_clipboard(&self) {
+        self.dispatch(AppTrayIcon::translate_from_clipboard);
+    }
+
+    fn translate_text(&self, text: String) {
+        self.dispatch(move |app| {
+            let window = window_manager::show_translation_window(app);
+            app.state::<EventsManager>()
+                .emit_translate_given_text_command(
+                    &window,
+                    TranslateTextCommandData {
+                        text: Some(text),
+                        ..Default::default()
+                    },
+                );
+        });
+    }
+
+    fn play_text(&self, text: String) {
+        self.dispatch(move |app| {
+            let (window, _) = window_manager::get_or_create_translation_window(app, true);
+            app.state::<EventsManager>()
+                .emit_play_given_text_command(&window, Some(text));
+        });
+    }
+
+    fn show_history(&self) {
+        self.dispatch(|app| {
+            window_manager::show_history_window(app);
+        });
+    }
+
+    // Shortcuts can only be registered on the main thread
+    fn toggle_suspend(&self) {
+        let app = self.app.clone();
+        self.app
+            .run_on_main_thread(move || app.state::<ShortcutsManager>().toggle_suspend())
+            .unwrap_or_else(|err| warn!("Unable to toggle suspend from D-Bus. {}", err));
+    }
+
+    #[zbus(signal)]
+    async fn suspend_changed(ctxt: &SignalContext<'_>, suspended: bool) -> zbus::Result<()>;
+
+    // Settings aren't sent with the signal, since they contain credentials
+    #[zbus(signal)]
+    async fn settings_changed(ctxt: &SignalContext<'_>) -> zbus::Result<()>;
+}
+
+impl DbusInterface {
+    // Methods are called on the zbus executor, so blocking work (text extraction, OCR) is moved to Tauri runtime
+    fn dispatch(&self, action: impl FnOnce(&AppHandle) + Send + 'static) {
+        let app = self.app.clone();
+        tauri::async_runtime::spawn_blocking(move || action(&app));
+    }
+}
+
+pub fn start(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match connect(&app).await {
+            Ok(connection) => {
+                info!("D-Bus service {SERVICE_NAME} is started.");
+                watch_state_changes(&app, connection);
+            }
+            // Session bus might be missing (e.g. in minimal environments), app works without it
+            Err(err) => warn!("Unable to start D-Bus service. {}", err),
+        }
+    });
+}
+
+async fn connect(app: &AppHandle) -> zbus::Result<Connection> {
+    connection::Builder::session()?
+        .name(SERVICE_NAME)?
+        .serve_at(OBJECT_PATH, DbusInterface { app: app.clone() })?
+        .build()
+        .await
+}
+
+fn watch_state_changes(app: &AppHandle, connection: Connection) {
+    let connection_clone = connection.clone();
+    app.state::<ShortcutsManager>()
+        .add_suspend_change_handler(move |suspended| {
+            let connection = connection_clone.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(err) = emit_suspend_changed(&connection, suspended).await {
+                    warn!("Unable to emit D-Bus suspend change signal. {}", err);
+                }
+            });
+        });
+
+    app.state::<SettingsManager>()
+        .add_change_handler(move |_, _| {
+            let connection = connection.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(err) = emit_settings_changed(&connection).await {
+                    warn!("Unable to emit D-Bus settings change signal. {}", err);
+                }
+            });
+        });
+}
+
+async fn emit_suspend_changed(connection: &Connection, suspended: bool) -> zbus::Result<()> {
+    let ctxt = SignalContext::new(connection, OBJECT_PATH)?;
+    DbusInterface::suspend_changed(&ctxt, suspended).await
+}
+
+async fn emit_settings_changed(connection: &Connection) -> zbus::Result<()> {
+    let ctxt = SignalContext::new(connection, OBJECT_PATH)?;
+    DbusInterface::settings_changed(&ctxt).await
+}
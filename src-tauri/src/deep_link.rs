@@ -0,0 +1,85 @@
+use log::{info, warn};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_deep_link::DeepLinkExt;
+use url::Url;
+
+use crate::{
+    events_manager::{EventsManager, TranslateTextCommandData},
+    window_manager,
+};
+
+const DEEP_LINK_SCHEME: &str = "stt";
+
+const TRANSLATE_ROUTE: &str = "translate";
+const HISTORY_ROUTE: &str = "history";
+
+// Handles links like stt://translate?text=hello&from=en&to=de and stt://history?search=word
+pub fn setup(app: &AppHandle) {
+    // Installer registers scheme on Windows, but it has to be done at runtime in dev mode and on Linux
+    #[cfg(any(target_os = "linux", all(debug_assertions, windows)))]
+    if let Err(err) = app.deep_link().register_all() {
+        warn!("Unable to register deep link scheme. {}", err);
+    }
+
+    let app_handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+            handle_url(&app_handle, &url);
+        }
+    });
+
+    // App launched by a link receives it on startup instead of an event
+    if let Ok(Some(urls)) = app.deep_link().get_current() {
+        for url in urls {
+            handle_url(app, &url);
+        }
+    }
+}
+
+pub fn is_deep_link(arg: &str) -> bool {
+    arg.starts_with(&format!("{DEEP_LINK_SCHEME}://"))
+}
+
+fn handle_url(app: &AppHandle, url: &Url) {
+    if url.scheme() != DEEP_LINK_SCHEME {
+        warn!("Unsupported deep link scheme: {}", url.scheme());
+        return;
+    }
+
+    info!("Handling deep link to {:?}.", url.host_str());
+
+    let get_query_param = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.to_string())
+    };
+
+    match url.host_str() {
+        Some(TRANSLATE_ROUTE) => {
+            let Some(text) = get_query_param("text") else {
+                warn!("Deep link to translate doesn't contain text.");
+                return;
+            };
+
+            let window = window_manager::show_translation_window(app);
+            app.state::<EventsManager>()
+                .emit_translate_given_text_command(
+                    &window,
+                    TranslateTextCommandData {
+                        text: Some(text),
+                        source_language: get_query_param("from"),
+                        target_language: get_query_param("to"),
+                        ..Default::default()
+                    },
+                );
+        }
+        Some(HISTORY_ROUTE) => {
+            let window = window_manager::show_history_window(app);
+            if let Some(search) = get_query_param("search") {
+                app.state::<EventsManager>()
+                    .emit_search_history_command(&window, search);
+            }
+        }
+        route => warn!("Unsupported deep link route: {:?}", route),
+    }
+}
@@ -1,9 +1,13 @@
 use std::sync::Mutex;
 
 use serde::Serialize;
-use tauri::{AppHandle, Emitter, WebviewWindow};
+use tauri::{AppHandle, Emitter, Manager, WebviewWindow};
 
-use crate::settings::Settings;
+use crate::{
+    requests_executor::{PendingRequestKind, RequestsExecutor},
+    settings::Settings,
+    text_extractor::{SourceContext, TextExtractor},
+};
 
 const ACCENT_COLOR_CHANGED_EVENT: &str = "accent_color_changed";
 
@@ -11,6 +15,7 @@ const BEFORE_SHOW_EVENT: &str = "before_show";
 const TRANSLATE_TEXT_COMMAND: &str = "translate_text";
 const PLAY_TEXT_COMMAND: &str = "play_text";
 const SHOW_INPUT_COMMAND: &str = "show_input";
+const SEARCH_HISTORY_COMMAND: &str = "search_history";
 
 pub const PLAY_START_EVENT: &str = "play_start";
 pub const PLAY_STOP_EVENT: &str = "play_stop";
@@ -22,25 +27,38 @@ pub const RESUME_HOTKEYS_EVENT: &str = "resume_hotkeys";
 
 #[derive(Serialize, Clone)]
 pub enum TranslationCommands {
-    Translate(bool),
-    Play,
+    Translate(TranslateTextCommandData),
+    Play(PlayTextCommandData),
     ShowInput,
 }
 
 pub struct EventsManager {
     // Stores last translation command, so translation window can check it upon initial load
     pub last_translation_command: Mutex<Option<TranslationCommands>>,
+    // Search requested for history window, it's taken by the window once applied
+    pub pending_history_search: Mutex<Option<String>>,
 }
 
-#[derive(Serialize, Clone)]
-struct TranslateTextCommandData {
+// Text is taken from clipboard, unless it's provided explicitly (e.g. by external tools)
+#[derive(Serialize, Clone, Default)]
+pub struct TranslateTextCommandData {
     pub show_definition: bool,
+    pub text: Option<String>,
+    pub source_language: Option<String>,
+    pub target_language: Option<String>,
+    pub source_context: Option<SourceContext>,
+}
+
+#[derive(Serialize, Clone, Default)]
+pub struct PlayTextCommandData {
+    pub text: Option<String>,
 }
 
 impl EventsManager {
     pub fn new() -> Self {
         Self {
             last_translation_command: Mutex::new(None),
+            pending_history_search: Mutex::new(None),
         }
     }
 
@@ -53,18 +71,42 @@ impl EventsManager {
     }
 
     pub fn emit_translate_text_command(&self, window: &WebviewWindow, show_definition: bool) {
+        self.emit_translate_given_text_command(
+            window,
+            TranslateTextCommandData {
+                show_definition,
+                source_context: window.state::<TextExtractor>().source_context(),
+                ..Default::default()
+            },
+        );
+    }
+
+    pub fn emit_translate_given_text_command(
+        &self,
+        window: &WebviewWindow,
+        command_data: TranslateTextCommandData,
+    ) {
+        // Results of older requests would be overwritten anyway, so there is no point waiting for them
+        window
+            .state::<RequestsExecutor>()
+            .cancel_pending_requests(PendingRequestKind::Translation);
+
         window
-            .emit(
-                TRANSLATE_TEXT_COMMAND,
-                TranslateTextCommandData { show_definition },
-            )
+            .emit(TRANSLATE_TEXT_COMMAND, command_data.clone())
             .unwrap();
-        self.set_last_translation_command(TranslationCommands::Translate(show_definition));
+        self.set_last_translation_command(TranslationCommands::Translate(command_data));
     }
 
     pub fn emit_play_text_command(&self, window: &WebviewWindow) {
-        window.emit(PLAY_TEXT_COMMAND, ()).unwrap();
-        self.set_last_translation_command(TranslationCommands::Play);
+        self.emit_play_given_text_command(window, None);
+    }
+
+    pub fn emit_play_given_text_command(&self, window: &WebviewWindow, text: Option<String>) {
+        let command_data = PlayTextCommandData { text };
+        window
+            .emit(PLAY_TEXT_COMMAND, command_data.clone())
+            .unwrap();
+        self.set_last_translation_command(TranslationCommands::Play(command_data));
     }
 
     pub fn emit_show_input_command(&self, window: &WebviewWindow) {
@@ -72,6 +114,11 @@ impl EventsManager {
         self.set_last_translation_command(TranslationCommands::ShowInput);
     }
 
+    pub fn emit_search_history_command(&self, window: &WebviewWindow, search: String) {
+        *self.pending_history_search.lock().unwrap() = Some(search);
+        window.emit(SEARCH_HISTORY_COMMAND, ()).unwrap();
+    }
+
     pub fn emit_settings_changed_event(app: &AppHandle, settings: &Settings) {
         app.emit(SETTINGS_CHANGED_EVENT, settings).unwrap();
     }
@@ -0,0 +1,405 @@
+use std::{
+    error::Error,
+    fs::{self, OpenOptions},
+    io::{Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use log::{info, warn};
+use reqwest::header::{
+    HeaderMap, AUTHORIZATION, CONTENT_TYPE, COOKIE, PROXY_AUTHORIZATION, SET_COOKIE,
+};
+use serde::Serialize;
+use tauri::{async_runtime::Receiver, AppHandle, Manager};
+
+use crate::{
+    requests_executor::ResponseData,
+    settings::{Settings, SettingsManager},
+    translation_providers::ProviderRequest,
+};
+
+const HAR_FILENAME: &str = "requests.har";
+const HAR_VERSION: &str = "1.2";
+const HAR_CREATOR: &str = "Selected Text Translate";
+
+const MAX_ENTRIES_PER_FILE: usize = 200;
+const MAX_ROTATED_FILES: usize = 5;
+
+const REDACTED_VALUE: &str = "[REDACTED]";
+
+// Entries are written between header and footer, so file stays a valid HAR document after every append
+const HAR_FOOTER: &str = "\n]}}\n";
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarCreator {
+    name: String,
+    version: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarEntry {
+    started_date_time: String,
+    time: u128,
+    request: HarRequest,
+    response: HarResponse,
+    cache: HarCache,
+    timings: HarTimings,
+    #[serde(rename = "_error", skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarRequest {
+    method: String,
+    url: String,
+    http_version: String,
+    cookies: Vec<HarNameValue>,
+    headers: Vec<HarNameValue>,
+    query_string: Vec<HarNameValue>,
+    post_data: HarPostData,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarResponse {
+    status: u16,
+    status_text: String,
+    http_version: String,
+    cookies: Vec<HarNameValue>,
+    headers: Vec<HarNameValue>,
+    content: HarContent,
+    #[serde(rename = "redirectURL")]
+    redirect_url: String,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+struct HarNameValue {
+    name: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarPostData {
+    mime_type: String,
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarContent {
+    size: i64,
+    mime_type: String,
+    text: String,
+}
+
+#[derive(Serialize)]
+struct HarCache {}
+
+#[derive(Serialize)]
+struct HarTimings {
+    send: i64,
+    wait: u128,
+    receive: i64,
+}
+
+// Records requests and responses in HAR format, so they can be inspected in browser dev tools
+// Every app launch starts a new file, previous files are kept up to a limit
+pub struct HarRecorder {
+    app: AppHandle,
+    har_flush_sender: tauri::async_runtime::Sender<HarEntry>,
+}
+
+// Appends entries to the current HAR file and rotates it once it's full
+struct HarWriter {
+    har_path: PathBuf,
+    header: String,
+    entries_in_file: usize,
+}
+
+impl HarRecorder {
+    pub fn new(app: &AppHandle) -> Self {
+        let (sender, receiver) = tauri::async_runtime::channel::<HarEntry>(1000);
+        HarRecorder::rotate_har_files(&HarRecorder::get_har_path(app));
+        HarRecorder::spawn_har_flush_receiver(app, receiver);
+
+        Self {
+            app: app.clone(),
+            har_flush_sender: sender,
+        }
+    }
+
+    pub fn record(
+        &self,
+        request: &ProviderRequest,
+        response: Result<&ResponseData, &reqwest::Error>,
+        started_at: DateTime<Utc>,
+        duration: Duration,
+    ) {
+        let settings = self.app.state::<SettingsManager>().read_settings();
+        let secrets = Self::get_secrets(&settings);
+
+        let entry = HarEntry {
+            started_date_time: started_at.to_rfc3339_opts(SecondsFormat::Millis, true),
+            time: duration.as_millis(),
+            request: Self::build_har_request(request, &secrets),
+            response: Self::build_har_response(response.ok(), &secrets),
+            cache: HarCache {},
+            timings: HarTimings {
+                send: 0,
+                wait: duration.as_millis(),
+                receive: 0,
+            },
+            error: response
+                .err()
+                .map(|err| Self::redact(&err.to_string(), &secrets)),
+        };
+
+        // Recording happens in async context, so blocking send can't be used here
+        if let Err(err) = self.har_flush_sender.try_send(entry) {
+            warn!("Unable to schedule HAR file flush. {}", err);
+        }
+    }
+
+    fn build_har_request(request: &ProviderRequest, secrets: &[String]) -> HarRequest {
+        let url = reqwest::Url::parse(&request.url).ok();
+        let query_string = url
+            .map(|url| {
+                url.query_pairs()
+                    .map(|(name, value)| HarNameValue {
+                        name: name.to_string(),
+                        value: Self::redact(&value, secrets),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        HarRequest {
+            method: "POST".to_string(),
+            url: Self::redact(&request.url, secrets),
+            http_version: "HTTP/1.1".to_string(),
+            cookies: Vec::new(),
+            headers: Self::build_har_headers(&request.headers, secrets),
+            query_string,
+            post_data: HarPostData {
+                mime_type: Self::get_content_type(&request.headers),
+                text: Self::redact(&request.body, secrets),
+            },
+            headers_size: -1,
+            body_size: request.body.len() as i64,
+        }
+    }
+
+    fn build_har_response(response: Option<&ResponseData>, secrets: &[String]) -> HarResponse {
+        let Some(response) = response else {
+            // HAR requires response to be present, zero status is used for failed requests
+            return HarResponse {
+                status: 0,
+                status_text: String::new(),
+                http_version: String::new(),
+                cookies: Vec::new(),
+                headers: Vec::new(),
+                content: HarContent {
+                    size: 0,
+                    mime_type: String::new(),
+                    text: String::new(),
+                },
+                redirect_url: String::new(),
+                headers_size: -1,
+                body_size: -1,
+            };
+        };
+
+        HarResponse {
+            status: response.status.as_u16(),
+            status_text: response
+                .status
+                .canonical_reason()
+                .unwrap_or_default()
+                .to_string(),
+            http_version: format!("{:?}", response.version),
+            cookies: Vec::new(),
+            headers: Self::build_har_headers(&response.headers, secrets),
+            content: HarContent {
+                size: response.body.len() as i64,
+                mime_type: Self::get_content_type(&response.headers),
+                text: Self::redact(&response.body, secrets),
+            },
+            redirect_url: String::new(),
+            headers_size: -1,
+            body_size: response.body.len() as i64,
+        }
+    }
+
+    fn build_har_headers(headers: &HeaderMap, secrets: &[String]) -> Vec<HarNameValue> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let is_sensitive_header =
+                    [AUTHORIZATION, PROXY_AUTHORIZATION, COOKIE, SET_COOKIE].contains(name);
+                let value = if is_sensitive_header {
+                    REDACTED_VALUE.to_string()
+                } else {
+                    Self::redact(value.to_str().unwrap_or_default(), secrets)
+                };
+
+                HarNameValue {
+                    name: name.to_string(),
+                    value,
+                }
+            })
+            .collect()
+    }
+
+    fn get_content_type(headers: &HeaderMap) -> String {
+        headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    // Credentials might end up in urls, bodies or error messages, so they are masked everywhere
+    fn get_secrets(settings: &Settings) -> Vec<String> {
+        let proxy = &settings.core.proxy;
+        let provider = &settings.translation.provider;
+
+        [
+            proxy.username.clone(),
+            proxy.password.clone(),
+            Some(provider.deepl.api_key.clone()),
+            provider.libre_translate.api_key.clone(),
+            provider.open_ai.api_key.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|secret| !secret.is_empty())
+        .collect()
+    }
+
+    fn redact(value: &str, secrets: &[String]) -> String {
+        secrets.iter().fold(value.to_string(), |value, secret| {
+            value.replace(secret, REDACTED_VALUE)
+        })
+    }
+
+    fn rotate_har_files(har_path: &Path) {
+        if !har_path.exists() {
+            return;
+        }
+
+        // Several rotations can happen within the same millisecond, so index is added to keep names unique
+        let timestamp = Utc::now().format("%Y-%m-%d_%H-%M-%S-%3f");
+        let mut rotated_har_path = har_path.with_file_name(format!("requests_{timestamp}.har"));
+        let mut index = 1;
+        while rotated_har_path.exists() {
+            rotated_har_path = har_path.with_file_name(format!("requests_{timestamp}_{index}.har"));
+            index += 1;
+        }
+
+        if let Err(err) = fs::rename(har_path, &rotated_har_path) {
+            warn!("Unable to rotate HAR file. {}", err);
+            return;
+        }
+
+        HarRecorder::remove_old_har_files(har_path);
+    }
+
+    fn remove_old_har_files(har_path: &Path) {
+        let Some(har_dir) = har_path.parent() else {
+            return;
+        };
+        let Ok(dir_entries) = fs::read_dir(har_dir) else {
+            return;
+        };
+
+        // Timestamp in file name allows to sort files by creation time
+        let mut rotated_har_files: Vec<PathBuf> = dir_entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+                file_name.starts_with("requests_") && file_name.ends_with(".har")
+            })
+            .collect();
+        rotated_har_files.sort();
+
+        let files_to_remove = rotated_har_files.len().saturating_sub(MAX_ROTATED_FILES);
+        for path in rotated_har_files.into_iter().take(files_to_remove) {
+            info!("Removing old HAR file {:?}", path);
+            fs::remove_file(path).unwrap_or_else(|err| warn!("Unable to remove HAR file. {}", err));
+        }
+    }
+
+    fn spawn_har_flush_receiver(app: &AppHandle, mut receiver: Receiver<HarEntry>) {
+        let har_path = HarRecorder::get_har_path(app);
+
+        // Make sure logs dir exists
+        let har_dir = har_path.parent().unwrap();
+        fs::create_dir_all(har_dir).unwrap();
+
+        let creator = HarCreator {
+            name: HAR_CREATOR.to_string(),
+            version: app.package_info().version.to_string(),
+        };
+        let mut har_writer = HarWriter {
+            har_path,
+            header: format!(
+                "{{\"log\":{{\"version\":{},\"creator\":{},\"entries\":[\n",
+                serde_json::to_string(HAR_VERSION).unwrap(),
+                serde_json::to_string(&creator).unwrap()
+            ),
+            entries_in_file: 0,
+        };
+
+        tauri::async_runtime::spawn(async move {
+            while let Some(entry) = receiver.recv().await {
+                har_writer
+                    .append(&entry)
+                    .unwrap_or_else(|err| warn!("Unable to write HAR file. {}", err));
+            }
+        });
+    }
+
+    fn get_har_path(app: &AppHandle) -> PathBuf {
+        let logs_dir = app.path().app_log_dir().unwrap();
+        logs_dir.join(HAR_FILENAME)
+    }
+}
+
+impl HarWriter {
+    fn append(&mut self, entry: &HarEntry) -> Result<(), Box<dyn Error>> {
+        if self.entries_in_file >= MAX_ENTRIES_PER_FILE {
+            HarRecorder::rotate_har_files(&self.har_path);
+            self.entries_in_file = 0;
+        }
+
+        let entry_json = serde_json::to_string_pretty(entry)?;
+
+        // File might have been removed by user, in this case it's started over
+        if self.entries_in_file == 0 || !self.har_path.exists() {
+            fs::write(
+                &self.har_path,
+                format!("{}{}{}", self.header, entry_json, HAR_FOOTER),
+            )?;
+            self.entries_in_file = 1;
+            return Ok(());
+        }
+
+        // Footer is overwritten by the new entry and written again after it
+        let mut file = OpenOptions::new().write(true).open(&self.har_path)?;
+        file.seek(SeekFrom::End(-(HAR_FOOTER.len() as i64)))?;
+        file.write_all(format!(",\n{}{}", entry_json, HAR_FOOTER).as_bytes())?;
+        self.entries_in_file += 1;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use std::error::Error;
+
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    MapVirtualKeyW, SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYBD_EVENT_FLAGS,
+    KEYEVENTF_KEYUP, MAPVK_VK_TO_VSC, VIRTUAL_KEY, VK_C, VK_CONTROL, VK_V,
+};
+
+pub fn send_copy_command() -> Result<(), Box<dyn Error>> {
+    send_control_shortcut(VK_C)
+}
+
+pub fn send_paste_command() -> Result<(), Box<dyn Error>> {
+    send_control_shortcut(VK_V)
+}
+
+fn send_control_shortcut(key: VIRTUAL_KEY) -> Result<(), Box<dyn Error>> {
+    const CBSIZE: i32 = std::mem::size_of::<INPUT>() as i32;
+
+    // Initialize inputs vector with default settings, to reduce boilerplate
+    // Actual keys are set below
+    let mut inputs: Vec<INPUT> = vec![];
+    for _ in 0..4 {
+        let input = INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: VIRTUAL_KEY(0),
+                    wScan: 0,
+                    dwFlags: KEYBD_EVENT_FLAGS(0),
+                    time: 0,
+                    dwExtraInfo: 0,
+                },
+            },
+        };
+
+        inputs.push(input);
+    }
+
+    unsafe {
+        // Ctrl + key combination
+        // Ctrl down
+        inputs[0].Anonymous.ki.wVk = VK_CONTROL;
+        inputs[0].Anonymous.ki.wScan = MapVirtualKeyW(VK_CONTROL.0 as u32, MAPVK_VK_TO_VSC) as u16;
+        // Key down
+        inputs[1].Anonymous.ki.wVk = key;
+        inputs[1].Anonymous.ki.wScan = MapVirtualKeyW(key.0 as u32, MAPVK_VK_TO_VSC) as u16;
+        // Ctrl up
+        inputs[2].Anonymous.ki.dwFlags = KEYEVENTF_KEYUP;
+        inputs[2].Anonymous.ki.wVk = inputs[0].Anonymous.ki.wVk;
+        inputs[2].Anonymous.ki.wScan = inputs[0].Anonymous.ki.wScan;
+        // Key up
+        inputs[3].Anonymous.ki.dwFlags = KEYEVENTF_KEYUP;
+        inputs[3].Anonymous.ki.wVk = inputs[1].Anonymous.ki.wVk;
+        inputs[3].Anonymous.ki.wScan = inputs[1].Anonymous.ki.wScan;
+
+        let result = SendInput(&mut inputs, CBSIZE);
+
+        if result == 0 {
+            return Err("Error executing SendInput".into());
+        }
+    };
+
+    Ok(())
+}
@@ -2,7 +2,11 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use accent_color_provider::AccentColorProvider;
+use api_server::ApiServer;
+use circuit_breaker::CircuitBreaker;
+use clipboard_watcher::ClipboardWatcher;
 use events_manager::EventsManager;
+use har_recorder::HarRecorder;
 use log::error;
 use shortcuts_manager::ShortcutsManager;
 use tauri::Manager;
@@ -10,18 +14,42 @@ use tauri_plugin_autostart::MacosLauncher;
 use tauri_plugin_log::{RotationStrategy, Target, TargetKind};
 
 mod accent_color_provider;
+mod api_server;
+mod circuit_breaker;
+mod cli;
+#[cfg(windows)]
+mod clipboard_snapshot;
+mod clipboard_watcher;
 mod commands;
+#[cfg(target_os = "linux")]
+mod dbus_service;
+mod deep_link;
 mod events_manager;
+mod har_recorder;
+#[cfg(windows)]
+mod input_injection;
 mod notifications;
+mod ocr;
+mod quick_copy;
 mod requests_executor;
+mod requests_mock;
+mod selection_translator;
 mod settings;
 mod shortcuts_manager;
 mod text_extractor;
+mod text_normalizer;
+mod text_replacer;
+mod translation_cache;
+mod translation_providers;
+mod translator;
 mod tray_icon;
 mod window_manager;
 
+use requests_executor::RequestsExecutor;
+use requests_mock::RequestsMock;
 use settings::SettingsManager;
 use text_extractor::TextExtractor;
+use translation_cache::TranslationCache;
 use tray_icon::AppTrayIcon;
 
 fn main() {
@@ -30,7 +58,11 @@ fn main() {
     }));
 
     tauri::Builder::default()
-        .plugin(tauri_plugin_single_instance::init(|_app, _args, _cwd| {}))
+        .plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
+            // Second launch forwards its arguments to the running instance and exits
+            cli::execute_args(app, &args);
+        }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(
             tauri_plugin_log::Builder::new()
@@ -64,16 +96,35 @@ fn main() {
             app.manage(events_manager);
             app.manage(ShortcutsManager::new(app.handle()));
             app.manage(TextExtractor::new(app.handle()));
+            app.manage(TranslationCache::new(app.handle()));
+            app.manage(CircuitBreaker::new(app.handle()));
+            app.manage(HarRecorder::new(app.handle()));
+            app.manage(RequestsMock::new(app.handle()));
+            app.manage(RequestsExecutor::new(app.handle()));
+            app.manage(ClipboardWatcher::new(app.handle()));
             app.manage(AppTrayIcon::new(app.handle()));
+            app.manage(ApiServer::new(app.handle()));
             app.manage(AccentColorProvider::new(app.handle()));
 
+            deep_link::setup(app.handle());
+
+            #[cfg(target_os = "linux")]
+            dbus_service::start(app.handle());
+
+            let args: Vec<String> = std::env::args().collect();
+            cli::execute_args(app.handle(), &args);
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             commands::accent_color,
             commands::clipboard_text,
             commands::execute_google_translate_request,
+            commands::translate_result,
+            commands::test_proxy,
+            commands::cancel_request,
             commands::last_translation_command,
+            commands::take_pending_history_search,
             commands::settings,
             commands::update_settings,
             commands::default_settings,
@@ -0,0 +1,232 @@
+use std::{
+    error::Error,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use log::info;
+use tauri::image::Image;
+
+use crate::settings::OcrSettings;
+
+// Recognizes text in image with local Tesseract installation
+// Image is passed as PPM via stdin, since it's supported by Tesseract and doesn't need encoding library or temporary file
+// Recognition takes a while, so it must not be called on the main thread
+pub fn recognize_text(
+    image: &Image,
+    settings: &OcrSettings,
+    source_language: &str,
+) -> Result<String, Box<dyn Error>> {
+    let languages = get_tesseract_languages(source_language);
+    info!("Recognizing text in clipboard image with {languages} languages.");
+
+    let mut command = Command::new(&settings.tesseract_path);
+    command
+        .arg("stdin")
+        .arg("stdout")
+        .arg("-l")
+        .arg(&languages)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    // Prevents console window from flashing
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = command.spawn().map_err(|err| {
+        format!(
+            "Unable to run Tesseract at \"{}\". {}",
+            settings.tesseract_path, err
+        )
+    })?;
+
+    // Tesseract reads the whole image before writing anything, so stdout can't fill up while image is written
+    // Stdin is closed once dropped, so Tesseract knows the image is complete
+    if let Some(mut stdin) = child.stdin.take() {
+        // Write error means that Tesseract exited early, the reason is reported with its status below
+        stdin.write_all(&encode_ppm(image)).ok();
+    }
+
+    let output = child.wait_with_output()?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Tesseract failed with {}. {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+        .into());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+// Transparent pixels are blended with white background, since PPM has no alpha channel
+fn encode_ppm(image: &Image) -> Vec<u8> {
+    let header = format!("P6\n{} {}\n255\n", image.width(), image.height());
+    let mut data = Vec::with_capacity(header.len() + image.rgba().len() / 4 * 3);
+    data.extend_from_slice(header.as_bytes());
+
+    for pixel in image.rgba().chunks_exact(4) {
+        let alpha = pixel[3] as u32;
+        for &channel in &pixel[..3] {
+            data.push(((channel as u32 * alpha + 255 * (255 - alpha)) / 255) as u8);
+        }
+    }
+
+    data
+}
+
+// English is always included, since images often mix it with other languages (e.g. in UI)
+fn get_tesseract_languages(source_language: &str) -> String {
+    match get_tesseract_language(source_language) {
+        Some(language) if language != "eng" => format!("{language}+eng"),
+        _ => "eng".to_string(),
+    }
+}
+
+// Maps translation language codes to Tesseract ones
+fn get_tesseract_language(language: &str) -> Option<&'static str> {
+    let tesseract_language = match language {
+        "af" => "afr",
+        "am" => "amh",
+        "ar" => "ara",
+        "az" => "aze",
+        "be" => "bel",
+        "bg" => "bul",
+        "bn" => "ben",
+        "bs" => "bos",
+        "ca" => "cat",
+        "ceb" => "ceb",
+        "co" => "cos",
+        "cs" => "ces",
+        "cy" => "cym",
+        "da" => "dan",
+        "de" => "deu",
+        "el" => "ell",
+        "en" => "eng",
+        "eo" => "epo",
+        "es" => "spa",
+        "et" => "est",
+        "eu" => "eus",
+        "fa" => "fas",
+        "fi" => "fin",
+        "fr" => "fra",
+        "fy" => "fry",
+        "ga" => "gle",
+        "gd" => "gla",
+        "gl" => "glg",
+        "gu" => "guj",
+        "hi" => "hin",
+        "hr" => "hrv",
+        "ht" => "hat",
+        "hu" => "hun",
+        "hy" => "hye",
+        "id" => "ind",
+        "is" => "isl",
+        "it" => "ita",
+        "iw" => "heb",
+        "ja" => "jpn",
+        "jw" => "jav",
+        "ka" => "kat",
+        "kk" => "kaz",
+        "km" => "khm",
+        "kn" => "kan",
+        "ko" => "kor",
+        "ku" => "kmr",
+        "ky" => "kir",
+        "la" => "lat",
+        "lb" => "ltz",
+        "lo" => "lao",
+        "lt" => "lit",
+        "lv" => "lav",
+        "mi" => "mri",
+        "mk" => "mkd",
+        "ml" => "mal",
+        "mn" => "mon",
+        "mr" => "mar",
+        "ms" => "msa",
+        "mt" => "mlt",
+        "my" => "mya",
+        "ne" => "nep",
+        "nl" => "nld",
+        "no" => "nor",
+        "pa" => "pan",
+        "pl" => "pol",
+        "ps" => "pus",
+        "pt" => "por",
+        "ro" => "ron",
+        "ru" => "rus",
+        "sd" => "snd",
+        "si" => "sin",
+        "sk" => "slk",
+        "sl" => "slv",
+        "sq" => "sqi",
+        "sr" => "srp",
+        "su" => "sun",
+        "sv" => "swe",
+        "sw" => "swa",
+        "ta" => "tam",
+        "te" => "tel",
+        "tg" => "tgk",
+        "th" => "tha",
+        "tl" => "tgl",
+        "tr" => "tur",
+        "uk" => "ukr",
+        "ur" => "urd",
+        "uz" => "uzb",
+        "vi" => "vie",
+        "yi" => "yid",
+        "yo" => "yor",
+        "zh-CN" => "chi_sim",
+        _ => return None,
+    };
+
+    Some(tesseract_language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_ppm_header() {
+        let image = Image::new_owned(vec![0; 3 * 2 * 4], 3, 2);
+        let ppm = encode_ppm(&image);
+
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(ppm.len(), b"P6\n3 2\n255\n".len() + 3 * 2 * 3);
+    }
+
+    #[test]
+    fn blends_transparent_pixels_with_white() {
+        let image = Image::new_owned(vec![10, 20, 30, 255, 10, 20, 30, 0], 2, 1);
+        let ppm = encode_ppm(&image);
+
+        assert_eq!(
+            &ppm[b"P6\n2 1\n255\n".len()..],
+            &[10, 20, 30, 255, 255, 255]
+        );
+    }
+
+    #[test]
+    fn combines_source_language_with_english() {
+        assert_eq!(get_tesseract_languages("ru"), "rus+eng");
+        assert_eq!(get_tesseract_languages("zh-CN"), "chi_sim+eng");
+    }
+
+    #[test]
+    fn uses_english_only_once() {
+        assert_eq!(get_tesseract_languages("en"), "eng");
+    }
+
+    #[test]
+    fn falls_back_to_english_for_unsupported_language() {
+        assert_eq!(get_tesseract_languages("auto"), "eng");
+    }
+}
@@ -0,0 +1,23 @@
+use std::error::Error;
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use crate::{
+    clipboard_watcher::ClipboardWatcher, notifications::show_notification, selection_translator,
+};
+
+// Translates selected text without showing the window and puts translation to clipboard
+pub fn copy_translation(app: &AppHandle) {
+    selection_translator::translate_selection(app, "Error copying translation", copy_to_clipboard);
+}
+
+async fn copy_to_clipboard(app: AppHandle, translation: String) -> Result<(), Box<dyn Error>> {
+    {
+        let _own_write = app.state::<ClipboardWatcher>().begin_own_write();
+        app.clipboard().write_text(translation.clone())?;
+    }
+
+    show_notification(&app, "Translation copied", Some(translation));
+    Ok(())
+}
@@ -1,40 +1,430 @@
-use std::time::Duration;
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
-use reqwest::header::{CONTENT_TYPE, ORIGIN, USER_AGENT};
+use chrono::Utc;
+use log::{info, warn};
+use rand::Rng;
+use reqwest::{header::HeaderMap, StatusCode};
 use tauri::{AppHandle, Manager};
+use tokio_util::sync::CancellationToken;
 
-use crate::settings::SettingsManager;
-
-pub async fn execute_google_translate_request(
-    app: &AppHandle,
-    url: String,
-    body: String,
-    user_agent: String,
-) -> Result<String, Box<dyn std::error::Error>> {
-    let settings_manager = app.state::<SettingsManager>();
-    let settings = settings_manager.read_settings();
-
-    let mut client_builder = reqwest::Client::builder();
-
-    if let Some(proxy) = settings.core.proxy {
-        client_builder = client_builder.proxy(reqwest::Proxy::https(proxy)?)
-    }
-
-    let response = client_builder
-        .build()?
-        .post(url)
-        .body(body)
-        .header(USER_AGENT, user_agent)
-        .header(ORIGIN, "https://translate.google.com")
-        .timeout(Duration::from_millis(
-            settings.core.request_timeout_milliseconds.into(),
-        ))
-        .header(
-            CONTENT_TYPE,
-            "application/x-www-form-urlencoded;charset=UTF-8",
-        )
-        .send()
-        .await?;
-
-    Ok(response.text().await?)
+use crate::{
+    circuit_breaker::CircuitBreaker,
+    har_recorder::HarRecorder,
+    requests_mock::RequestsMock,
+    settings::{
+        CoreSettings, ProxyMode, ProxyScheme, ProxySettings, RequestsMockMode, SettingsManager,
+    },
+    translation_providers::{self, GoogleTranslateProvider, ProviderRequest},
+};
+
+const MAX_RETRY_DELAY_MILLISECONDS: u64 = 30_000;
+
+// Google serves this page instead of the actual response once it considers traffic automated
+const CAPTCHA_PAGE_PATH: &str = "/sorry/";
+
+pub struct ResponseData {
+    pub status: StatusCode,
+    pub version: reqwest::Version,
+    pub url: reqwest::Url,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub enum RequestError {
+    InvalidUrl(url::ParseError),
+    Http(reqwest::Error),
+    UnsuccessfulStatus(StatusCode),
+    Blocked(StatusCode),
+    CircuitOpen(Duration),
+    FixtureNotFound(String),
+    HostNotAllowed(String),
+    Cancelled,
+}
+
+impl Display for RequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestError::InvalidUrl(err) => write!(f, "Invalid request url: {err}"),
+            RequestError::Http(err) => write!(f, "Request failed: {err}"),
+            RequestError::UnsuccessfulStatus(status) => {
+                write!(f, "Request failed with status {status}")
+            }
+            RequestError::Blocked(status) => write!(
+                f,
+                "Request was rejected by the service with status {status}"
+            ),
+            RequestError::CircuitOpen(remaining_cooldown) => write!(
+                f,
+                "Requests are paused for {} more seconds",
+                remaining_cooldown.as_secs()
+            ),
+            RequestError::FixtureNotFound(url) => {
+                write!(f, "No recorded fixture found for request to {url}")
+            }
+            RequestError::HostNotAllowed(host) => {
+                write!(f, "Requests to {host} are not allowed")
+            }
+            RequestError::Cancelled => write!(f, "Request was cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for RequestError {}
+
+impl From<reqwest::Error> for RequestError {
+    fn from(err: reqwest::Error) -> Self {
+        RequestError::Http(err)
+    }
+}
+
+impl From<url::ParseError> for RequestError {
+    fn from(err: url::ParseError) -> Self {
+        RequestError::InvalidUrl(err)
+    }
+}
+
+impl RequestError {
+    // Returned to the frontend along with the message, so errors can be handled without parsing messages
+    pub fn kind(&self) -> &'static str {
+        match self {
+            RequestError::InvalidUrl(_) => "invalidUrl",
+            RequestError::Http(_) => "http",
+            RequestError::UnsuccessfulStatus(_) => "unsuccessfulStatus",
+            RequestError::Blocked(_) => "blocked",
+            RequestError::CircuitOpen(_) => "circuitOpen",
+            RequestError::FixtureNotFound(_) => "fixtureNotFound",
+            RequestError::HostNotAllowed(_) => "hostNotAllowed",
+            RequestError::Cancelled => "cancelled",
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        match self {
+            RequestError::Http(err) => err.is_timeout() || err.is_connect(),
+            RequestError::UnsuccessfulStatus(status) => status.is_server_error(),
+            _ => false,
+        }
+    }
+}
+
+// Translation requests are superseded by newer translations, while other requests (e.g. playback) are not
+#[derive(Clone, Copy, PartialEq)]
+pub enum PendingRequestKind {
+    Translation,
+    Other,
+}
+
+// Client is shared between requests to reuse connection pool, TLS sessions and HTTP/2 connections
+// It's rebuilt only when settings affecting it are changed
+#[derive(Clone)]
+pub struct RequestsExecutor {
+    app: AppHandle,
+    client: Arc<Mutex<Option<reqwest::Client>>>,
+    // Requests started by the frontend, keyed by request id
+    pending_requests: Arc<Mutex<HashMap<String, (PendingRequestKind, CancellationToken)>>>,
+}
+
+impl RequestsExecutor {
+    pub fn new(app: &AppHandle) -> Self {
+        let requests_executor = Self {
+            app: app.clone(),
+            client: Arc::new(Mutex::new(None)),
+            pending_requests: Arc::new(Mutex::new(HashMap::new())),
+        };
+
+        let settings_manager = app.state::<SettingsManager>();
+        let self_clone = requests_executor.clone();
+        settings_manager.add_change_handler(move |old_settings, new_settings| {
+            if Self::are_client_settings_changed(&old_settings.core, &new_settings.core) {
+                self_clone.reset_client();
+            }
+        });
+
+        requests_executor
+    }
+
+    // Runs request future until it completes or is cancelled by id
+    // Dropping the future aborts an in-flight HTTP request
+    pub async fn execute_cancellable<T, E: From<RequestError>>(
+        &self,
+        request_id: String,
+        request_kind: PendingRequestKind,
+        request: impl Future<Output = Result<T, E>>,
+    ) -> Result<T, E> {
+        let cancellation_token = CancellationToken::new();
+        self.pending_requests.lock().unwrap().insert(
+            request_id.clone(),
+            (request_kind, cancellation_token.clone()),
+        );
+
+        let result = tokio::select! {
+            result = request => result,
+            _ = cancellation_token.cancelled() => Err(RequestError::Cancelled.into()),
+        };
+
+        self.pending_requests.lock().unwrap().remove(&request_id);
+
+        result
+    }
+
+    pub fn cancel_request(&self, request_id: &str) {
+        if let Some((_, cancellation_token)) =
+            self.pending_requests.lock().unwrap().remove(request_id)
+        {
+            info!("Cancelling request {request_id}.");
+            cancellation_token.cancel();
+        }
+    }
+
+    pub fn cancel_pending_requests(&self, request_kind: PendingRequestKind) {
+        let mut pending_requests = self.pending_requests.lock().unwrap();
+        pending_requests.retain(|request_id, (pending_request_kind, cancellation_token)| {
+            if *pending_request_kind != request_kind {
+                return true;
+            }
+
+            info!("Cancelling pending request {request_id}.");
+            cancellation_token.cancel();
+            false
+        });
+    }
+
+    pub async fn execute_google_translate_request(
+        &self,
+        url: String,
+        body: String,
+        user_agent: String,
+    ) -> Result<String, RequestError> {
+        let parsed_url = reqwest::Url::parse(&url)?;
+        let settings = self.app.state::<SettingsManager>().read_settings();
+        if !translation_providers::is_raw_request_allowed(
+            &settings.translation.provider,
+            &parsed_url,
+        ) {
+            let host = parsed_url.host_str().unwrap_or_default().to_string();
+            warn!(
+                "Rejected request to not allowed host '{host}' with scheme '{}'.",
+                parsed_url.scheme()
+            );
+            return Err(RequestError::HostNotAllowed(host));
+        }
+
+        let request = GoogleTranslateProvider::build_raw_request(url, body, &user_agent);
+        self.execute_request(request).await
+    }
+
+    pub async fn execute_request(&self, request: ProviderRequest) -> Result<String, RequestError> {
+        let requests_mock = self.app.state::<RequestsMock>();
+        match requests_mock.mock_mode() {
+            RequestsMockMode::Off => self.execute_request_internal(&request).await,
+            RequestsMockMode::Record => {
+                let response = self.execute_request_internal(&request).await?;
+                requests_mock.record(&request, &response);
+                Ok(response)
+            }
+            RequestsMockMode::Replay => requests_mock
+                .replay(&request)
+                .ok_or_else(|| RequestError::FixtureNotFound(request.url.clone())),
+        }
+    }
+
+    async fn execute_request_internal(
+        &self,
+        request: &ProviderRequest,
+    ) -> Result<String, RequestError> {
+        let settings = self.app.state::<SettingsManager>().read_settings().core;
+        let circuit_breaker = self.app.state::<CircuitBreaker>();
+
+        let host = reqwest::Url::parse(&request.url)?
+            .host_str()
+            .unwrap_or_default()
+            .to_string();
+
+        if let Some(remaining_cooldown) = circuit_breaker.remaining_cooldown(&host) {
+            return Err(RequestError::CircuitOpen(remaining_cooldown));
+        }
+
+        let client = self.get_client(&settings)?;
+
+        let mut attempt = 0;
+        loop {
+            let result = self.send_request(&client, request).await;
+
+            match result {
+                Err(RequestError::Blocked(status)) => {
+                    circuit_breaker.open(
+                        &host,
+                        Duration::from_secs(settings.circuit_breaker_cooldown_seconds),
+                    );
+                    return Err(RequestError::Blocked(status));
+                }
+                Err(err) if err.is_retryable() && attempt < settings.request_retries => {
+                    let delay = Self::get_retry_delay(&settings, attempt);
+                    warn!(
+                        "Request to {host} failed, retrying in {} ms. Attempt {}. Error: {err}",
+                        delay.as_millis(),
+                        attempt + 1
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    // Sends a single request through the provided proxy settings, bypassing shared client and retries
+    // Used to check proxy settings before they are saved
+    pub async fn execute_request_through_proxy(
+        &self,
+        request: ProviderRequest,
+        proxy_settings: &ProxySettings,
+    ) -> Result<String, RequestError> {
+        let settings = self.app.state::<SettingsManager>().read_settings().core;
+        let client = Self::build_client(&settings, proxy_settings)?;
+
+        self.send_request(&client, &request).await
+    }
+
+    fn get_client(&self, settings: &CoreSettings) -> Result<reqwest::Client, RequestError> {
+        let mut client = self.client.lock().unwrap();
+        if let Some(client) = client.as_ref() {
+            return Ok(client.clone());
+        }
+
+        let new_client = Self::build_client(settings, &settings.proxy)?;
+        *client = Some(new_client.clone());
+
+        Ok(new_client)
+    }
+
+    fn reset_client(&self) {
+        info!("Proxy or timeout settings changed. HTTP client will be rebuilt.");
+        *self.client.lock().unwrap() = None;
+    }
+
+    fn build_client(
+        settings: &CoreSettings,
+        proxy_settings: &ProxySettings,
+    ) -> Result<reqwest::Client, RequestError> {
+        let mut client_builder = reqwest::Client::builder().timeout(Duration::from_millis(
+            settings.request_timeout_milliseconds.into(),
+        ));
+
+        // Reqwest uses system proxy by default, so nothing needs to be configured for system mode
+        client_builder = match proxy_settings.mode {
+            ProxyMode::None => client_builder.no_proxy(),
+            ProxyMode::System => client_builder,
+            ProxyMode::Manual => client_builder.proxy(Self::build_proxy(proxy_settings)?),
+        };
+
+        Ok(client_builder.build()?)
+    }
+
+    fn build_proxy(proxy_settings: &ProxySettings) -> Result<reqwest::Proxy, RequestError> {
+        let scheme = match proxy_settings.scheme {
+            ProxyScheme::Http => "http",
+            ProxyScheme::Https => "https",
+            ProxyScheme::Socks5 => "socks5",
+        };
+
+        // Proxy is applied to all requests, since providers can be configured with plain http urls
+        let mut proxy = reqwest::Proxy::all(format!(
+            "{scheme}://{}:{}",
+            proxy_settings.host, proxy_settings.port
+        ))?
+        .no_proxy(reqwest::NoProxy::from_string(
+            &proxy_settings.bypass.join(","),
+        ));
+
+        if let Some(username) = &proxy_settings.username {
+            proxy = proxy.basic_auth(
+                username,
+                proxy_settings.password.as_deref().unwrap_or_default(),
+            );
+        }
+
+        Ok(proxy)
+    }
+
+    async fn send_request(
+        &self,
+        client: &reqwest::Client,
+        request: &ProviderRequest,
+    ) -> Result<String, RequestError> {
+        let settings = self.app.state::<SettingsManager>().read_settings().core;
+
+        let started_at = Utc::now();
+        let start_instant = Instant::now();
+        let result = Self::send_request_internal(client, request).await;
+
+        if settings.log_requests {
+            self.app.state::<HarRecorder>().record(
+                request,
+                result.as_ref(),
+                started_at,
+                start_instant.elapsed(),
+            );
+        }
+
+        let response = result?;
+
+        let is_captcha_page = response.url.path().starts_with(CAPTCHA_PAGE_PATH);
+        if response.status == StatusCode::TOO_MANY_REQUESTS || is_captcha_page {
+            return Err(RequestError::Blocked(response.status));
+        }
+
+        if !response.status.is_success() {
+            return Err(RequestError::UnsuccessfulStatus(response.status));
+        }
+
+        Ok(response.body)
+    }
+
+    async fn send_request_internal(
+        client: &reqwest::Client,
+        request: &ProviderRequest,
+    ) -> Result<ResponseData, reqwest::Error> {
+        let response = client
+            .post(&request.url)
+            .body(request.body.clone())
+            .headers(request.headers.clone())
+            .send()
+            .await?;
+
+        Ok(ResponseData {
+            status: response.status(),
+            version: response.version(),
+            url: response.url().clone(),
+            headers: response.headers().clone(),
+            body: response.text().await?,
+        })
+    }
+
+    fn are_client_settings_changed(
+        old_settings: &CoreSettings,
+        new_settings: &CoreSettings,
+    ) -> bool {
+        old_settings.proxy != new_settings.proxy
+            || old_settings.request_timeout_milliseconds
+                != new_settings.request_timeout_milliseconds
+    }
+
+    // Exponential backoff with random jitter, so parallel requests don't retry simultaneously
+    fn get_retry_delay(settings: &CoreSettings, attempt: u32) -> Duration {
+        let base_delay = settings
+            .request_retry_delay_milliseconds
+            .saturating_mul(2_u64.saturating_pow(attempt))
+            .min(MAX_RETRY_DELAY_MILLISECONDS);
+        let jitter = rand::thread_rng().gen_range(0..=base_delay / 2);
+
+        Duration::from_millis(base_delay + jitter)
+    }
 }
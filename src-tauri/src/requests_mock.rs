@@ -0,0 +1,155 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    settings::{RequestsMockMode, SettingsManager},
+    translation_providers::ProviderRequest,
+};
+
+// Environment variables take precedence over settings, so mock mode can be enabled without touching settings
+const MOCK_MODE_ENV_VARIABLE: &str = "STT_REQUESTS_MOCK_MODE";
+const FIXTURES_DIR_ENV_VARIABLE: &str = "STT_REQUESTS_FIXTURES_DIR";
+
+const DEFAULT_FIXTURES_DIR_NAME: &str = "request-fixtures";
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestFixture {
+    url: String,
+    body: String,
+    response: String,
+}
+
+// Allows to record responses as fixtures and serve them later without network access
+pub struct RequestsMock {
+    app: AppHandle,
+    env_mock_mode: Option<RequestsMockMode>,
+    fixtures_dir: PathBuf,
+}
+
+impl RequestsMock {
+    pub fn new(app: &AppHandle) -> Self {
+        let requests_mock = Self {
+            app: app.clone(),
+            env_mock_mode: Self::read_env_mock_mode(),
+            fixtures_dir: Self::get_fixtures_dir(app),
+        };
+
+        let mock_mode = requests_mock.mock_mode();
+        if mock_mode != RequestsMockMode::Off {
+            info!(
+                "Requests mock mode is {:?}. Fixtures directory: {:?}",
+                mock_mode, requests_mock.fixtures_dir
+            );
+        }
+
+        requests_mock
+    }
+
+    pub fn mock_mode(&self) -> RequestsMockMode {
+        self.env_mock_mode.unwrap_or_else(|| {
+            self.app
+                .state::<SettingsManager>()
+                .read_settings()
+                .core
+                .requests_mock_mode
+        })
+    }
+
+    pub fn replay(&self, request: &ProviderRequest) -> Option<String> {
+        let fixture_path = self.get_fixture_path(request);
+        let fixture = fs::read_to_string(&fixture_path)
+            .ok()
+            .and_then(|fixture_json| serde_json::from_str::<RequestFixture>(&fixture_json).ok());
+
+        if fixture.is_none() {
+            warn!(
+                "No fixture found for request to {}. Expected fixture: {:?}",
+                request.url, fixture_path
+            );
+        }
+
+        fixture.map(|fixture| fixture.response)
+    }
+
+    pub fn record(&self, request: &ProviderRequest, response: &str) {
+        self.try_record(request, response)
+            .unwrap_or_else(|err| warn!("Unable to record request fixture. {}", err));
+    }
+
+    fn try_record(&self, request: &ProviderRequest, response: &str) -> Result<(), Box<dyn Error>> {
+        let fixture = RequestFixture {
+            url: request.url.clone(),
+            body: request.body.clone(),
+            response: response.to_string(),
+        };
+
+        fs::create_dir_all(&self.fixtures_dir)?;
+        fs::write(
+            self.get_fixture_path(request),
+            serde_json::to_string_pretty(&fixture)?,
+        )?;
+
+        Ok(())
+    }
+
+    fn get_fixture_path(&self, request: &ProviderRequest) -> PathBuf {
+        self.fixtures_dir
+            .join(Self::get_fixture_name(&request.url, &request.body))
+    }
+
+    // Fixtures are matched by url and body, so file name is a stable hash of them
+    // Url is length prefixed, otherwise moving characters between url and body would produce the same hash
+    fn get_fixture_name(url: &str, body: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update((url.len() as u64).to_le_bytes());
+        hasher.update(url.as_bytes());
+        hasher.update(body.as_bytes());
+        let hash = hex::encode(hasher.finalize());
+
+        format!("{hash}.json")
+    }
+
+    fn read_env_mock_mode() -> Option<RequestsMockMode> {
+        let env_mock_mode = std::env::var(MOCK_MODE_ENV_VARIABLE).ok()?;
+
+        serde_json::from_value(Value::String(env_mock_mode.to_lowercase()))
+            .inspect_err(|err| warn!("Invalid {MOCK_MODE_ENV_VARIABLE} value. {err}"))
+            .ok()
+    }
+
+    fn get_fixtures_dir(app: &AppHandle) -> PathBuf {
+        std::env::var(FIXTURES_DIR_ENV_VARIABLE)
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| {
+                let data_dir = app.path().app_data_dir().unwrap();
+                data_dir.join(DEFAULT_FIXTURES_DIR_NAME)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixture_name_is_stable() {
+        assert_eq!(
+            RequestsMock::get_fixture_name("https://example.com/?q=1", "body"),
+            RequestsMock::get_fixture_name("https://example.com/?q=1", "body")
+        );
+    }
+
+    #[test]
+    fn fixture_name_separates_url_and_body() {
+        assert_ne!(
+            RequestsMock::get_fixture_name("https://example.com/?q=ab", "c"),
+            RequestsMock::get_fixture_name("https://example.com/?q=a", "bc")
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::{error::Error, future::Future};
+
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    notifications::show_error_notification,
+    settings::SettingsManager,
+    text_extractor::{show_no_text_selected_notification, TextExtractor},
+    translation_providers::TranslateRequest,
+    translator,
+};
+
+// Shared flow of hotkeys which translate selected text without showing the window (e.g. copy or replace)
+// Translation is passed to the action, errors of both translation and action are shown as notification
+pub fn translate_selection<F, Fut>(app: &AppHandle, error_title: &'static str, action: F)
+where
+    F: FnOnce(AppHandle, String) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<(), Box<dyn Error>>> + Send + 'static,
+{
+    let settings = app.state::<SettingsManager>().read_settings();
+    let text_extractor = app.state::<TextExtractor>();
+    if let Err(err) = text_extractor.copy_selected_text(&settings) {
+        show_error_notification(app, err, "Error extracting selected text");
+        return;
+    }
+
+    let text = text_extractor.text_from_clipboard();
+    if text.trim().is_empty() {
+        show_no_text_selected_notification(app);
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(err) = translate_and_apply(app.clone(), text, action).await {
+            show_error_notification(&app, err, error_title);
+        }
+    });
+}
+
+async fn translate_and_apply<F, Fut>(
+    app: AppHandle,
+    text: String,
+    action: F,
+) -> Result<(), Box<dyn Error>>
+where
+    F: FnOnce(AppHandle, String) -> Fut,
+    Fut: Future<Output = Result<(), Box<dyn Error>>>,
+{
+    let translation = translate(&app, text).await?;
+    action(app, translation).await
+}
+
+async fn translate(app: &AppHandle, text: String) -> Result<String, Box<dyn Error>> {
+    let settings = app.state::<SettingsManager>().read_settings().translation;
+    let request = TranslateRequest::from_settings(&settings, text);
+
+    translator::translate_sentence(app, &request).await
+}
@@ -2,6 +2,7 @@ use selected_text_translate_macros::{settings, Partial};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+mod migrations;
 mod settings_manager;
 
 pub use settings_manager::SettingsManager;
@@ -18,6 +19,8 @@ pub struct Settings {
     pub scaling: ScalingSettings,
     pub hotkeys: HotkeySettings,
     pub supabase: SupabaseSettings,
+    pub api_server: ApiServerSettings,
+    pub clipboard_watcher: ClipboardWatcherSettings,
 }
 
 #[skip_serializing_none]
@@ -33,18 +36,97 @@ pub struct PartialSettings {
     pub scaling: Option<PartialScalingSettings>,
     pub hotkeys: Option<PartialHotkeySettings>,
     pub supabase: Option<PartialSupabaseSettings>,
+    pub api_server: Option<PartialApiServerSettings>,
+    pub clipboard_watcher: Option<PartialClipboardWatcherSettings>,
 }
 
 #[settings]
 pub struct CoreSettings {
+    pub extraction_strategies: Vec<ExtractionStrategyKind>,
     pub copy_delay_milliseconds: u64,
+    pub preserve_clipboard: bool,
     pub request_timeout_milliseconds: u32,
+    pub request_retries: u32,
+    pub request_retry_delay_milliseconds: u64,
+    pub circuit_breaker_cooldown_seconds: u64,
     pub log_requests: bool,
+    pub requests_mock_mode: RequestsMockMode,
     pub play_volume: u32,
     pub search_pattern: String,
     pub last_records_to_scan_for_merge: u32,
     pub levenshtein_distance_for_merge: u8,
-    pub proxy: Option<String>,
+    pub proxy: ProxySettings,
+    pub translation_cache_max_entries: u32,
+    pub translation_cache_ttl_hours: u32,
+    pub text_normalization: TextNormalizationSettings,
+    pub ocr: OcrSettings,
+}
+
+#[settings]
+pub struct OcrSettings {
+    pub enabled: bool,
+    pub tesseract_path: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ExtractionStrategyKind {
+    // Ctrl + C is sent to the active app (Windows)
+    SyntheticCopy,
+    // PRIMARY selection is read (Linux)
+    PrimarySelection,
+    // Selection of the focused control is read via accessibility API (Windows)
+    UiAutomation,
+    // Current clipboard content is taken as is
+    Clipboard,
+}
+
+#[settings]
+pub struct TextNormalizationSettings {
+    pub join_hyphenated_words: bool,
+    pub join_wrapped_lines: bool,
+    pub strip_list_markers: bool,
+    pub normalize_characters: bool,
+    pub split_identifiers: bool,
+    pub max_length: u32,
+}
+
+#[settings]
+pub struct ProxySettings {
+    pub mode: ProxyMode,
+    pub scheme: ProxyScheme,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub bypass: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ProxyMode {
+    None,
+    // Proxy from environment variables or OS settings
+    System,
+    Manual,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ProxyScheme {
+    Http,
+    Https,
+    Socks5,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum RequestsMockMode {
+    Off,
+    // Responses are saved as fixtures
+    Record,
+    // Fixtures are served instead of sending requests
+    Replay,
 }
 
 #[settings]
@@ -52,6 +134,44 @@ pub struct TranslationSettings {
     pub source_language: String,
     pub target_language: String,
     pub tags: Vec<Tag>,
+    pub provider: TranslationProviderSettings,
+}
+
+#[settings]
+pub struct TranslationProviderSettings {
+    pub kind: TranslationProviderKind,
+    pub deepl: DeepLProviderSettings,
+    pub libre_translate: LibreTranslateProviderSettings,
+    pub open_ai: OpenAiProviderSettings,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TranslationProviderKind {
+    Google,
+    #[serde(rename = "deepl")]
+    DeepL,
+    LibreTranslate,
+    OpenAi,
+}
+
+#[settings]
+pub struct DeepLProviderSettings {
+    pub url: String,
+    pub api_key: String,
+}
+
+#[settings]
+pub struct LibreTranslateProviderSettings {
+    pub url: String,
+    pub api_key: Option<String>,
+}
+
+#[settings]
+pub struct OpenAiProviderSettings {
+    pub url: String,
+    pub api_key: Option<String>,
+    pub model: String,
 }
 
 #[settings]
@@ -122,8 +242,11 @@ pub struct HotkeySettings {
     pub translate: Vec<Keys>,
     pub play_text: Vec<Keys>,
     pub show_definition: Vec<Keys>,
+    pub translate_and_replace: Vec<Keys>,
+    pub copy_translation: Vec<Keys>,
     pub input_text: Vec<Keys>,
     pub toggle_suspend: Vec<Keys>,
+    pub toggle_clipboard_watch: Vec<Keys>,
     pub zoom_in: Vec<Keys>,
     pub zoom_out: Vec<Keys>,
     pub reset_zoom: Vec<Keys>,
@@ -139,6 +262,21 @@ pub struct SupabaseSettings {
     pub anon_key: String,
 }
 
+#[settings]
+pub struct ApiServerSettings {
+    pub enabled: bool,
+    pub port: u16,
+    pub auth_token: String,
+}
+
+#[settings]
+pub struct ClipboardWatcherSettings {
+    pub min_length: u32,
+    pub max_length: u32,
+    pub ignore_urls: bool,
+    pub debounce_milliseconds: u64,
+}
+
 pub trait UpdatableSettings
 where
     Self: Sized,
@@ -185,6 +323,14 @@ impl Settings {
                 user_settings.supabase.unwrap_or_default(),
                 default_settings.supabase,
             ),
+            api_server: ApiServerSettings::from(
+                user_settings.api_server.unwrap_or_default(),
+                default_settings.api_server,
+            ),
+            clipboard_watcher: ClipboardWatcherSettings::from(
+                user_settings.clipboard_watcher.unwrap_or_default(),
+                default_settings.clipboard_watcher,
+            ),
         }
     }
 }
@@ -213,6 +359,11 @@ impl PartialSettings {
             scaling: Self::update_settings(self.scaling.clone(), updated_settings.scaling),
             hotkeys: Self::update_settings(self.hotkeys.clone(), updated_settings.hotkeys),
             supabase: Self::update_settings(self.supabase.clone(), updated_settings.supabase),
+            api_server: Self::update_settings(self.api_server.clone(), updated_settings.api_server),
+            clipboard_watcher: Self::update_settings(
+                self.clipboard_watcher.clone(),
+                updated_settings.clipboard_watcher,
+            ),
         }
     }
 
@@ -1,12 +1,15 @@
+use log::{info, warn};
+use serde_json::Value;
 use std::error::Error;
 use std::ops::Deref;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{fs, sync::Mutex};
 use tauri::{async_runtime::Receiver, AppHandle, Manager};
 use tauri_plugin_shell::ShellExt;
 
 use crate::notifications::show_error_notification;
 
+use super::migrations;
 use super::PartialSettings;
 use super::Settings;
 
@@ -133,13 +136,56 @@ impl SettingsManager {
         let user_settings_path = SettingsManager::get_user_settings_path(&self.app);
 
         if user_settings_path.exists() {
-            let user_settings_json = fs::read_to_string(user_settings_path)?;
-            Ok(serde_json::from_str(&user_settings_json)?)
+            let user_settings_json = fs::read_to_string(&user_settings_path)?;
+            let mut user_settings: Value = serde_json::from_str(&user_settings_json)?;
+
+            let version = migrations::get_version(&user_settings);
+            if version > migrations::CURRENT_VERSION {
+                warn!(
+                    "User settings version {version} is newer than supported version {}, unknown settings are ignored.",
+                    migrations::CURRENT_VERSION
+                );
+            }
+
+            if version >= migrations::CURRENT_VERSION {
+                return Ok(serde_json::from_value(user_settings)?);
+            }
+
+            SettingsManager::backup_user_settings(&user_settings_path, version)?;
+            migrations::migrate(&mut user_settings)?;
+            let migrated_user_settings: PartialSettings = serde_json::from_value(user_settings)?;
+
+            // Migrated settings are written by the flush task, same as updates, so writes to the file don't race
+            // Flush isn't awaited, since settings might be read from async context
+            // If it fails, original file is left as is and migrations are run again on the next read
+            self.settings_flush_sender
+                .try_send(migrated_user_settings.clone())
+                .unwrap_or_else(|err| {
+                    warn!("Unable to schedule writing of migrated user settings. {err}")
+                });
+
+            Ok(migrated_user_settings)
         } else {
             Ok(PartialSettings::default())
         }
     }
 
+    // Original file is kept next to the migrated one, so settings can be recovered if migration goes wrong
+    fn backup_user_settings(user_settings_path: &Path, version: u64) -> Result<(), Box<dyn Error>> {
+        let backup_path =
+            user_settings_path.with_file_name(format!("settings.v{version}.backup.json"));
+        fs::copy(user_settings_path, &backup_path)?;
+
+        info!("User settings have been backed up to {:?}.", backup_path);
+        Ok(())
+    }
+
+    fn serialize_user_settings(user_settings: &PartialSettings) -> String {
+        let mut user_settings = serde_json::to_value(user_settings).unwrap();
+        migrations::set_version(&mut user_settings, migrations::CURRENT_VERSION);
+        serde_json::to_string_pretty(&user_settings).unwrap()
+    }
+
     fn spawn_settings_flush_receiver(app: &AppHandle, mut receiver: Receiver<PartialSettings>) {
         let settings_path = SettingsManager::get_user_settings_path(app);
 
@@ -150,7 +196,7 @@ impl SettingsManager {
         tauri::async_runtime::spawn(async move {
             loop {
                 while let Some(settings) = receiver.recv().await {
-                    let settings_json = serde_json::to_string_pretty(&settings).unwrap();
+                    let settings_json = SettingsManager::serialize_user_settings(&settings);
                     fs::write(settings_path.clone(), settings_json).unwrap();
                 }
             }
@@ -8,15 +8,26 @@ use tauri::{AppHandle, Listener, Manager};
 use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
 
 use crate::{
+    clipboard_watcher::ClipboardWatcher,
     events_manager::{EventsManager, PAUSE_HOTKEYS_EVENT, RESUME_HOTKEYS_EVENT},
     notifications::show_error_notification,
-    settings::{HotkeySettings, Keys, SettingsManager},
-    text_extractor::TextExtractor,
-    window_manager,
+    quick_copy,
+    settings::{HotkeySettings, Keys, Settings, SettingsManager},
+    text_extractor::{show_no_text_selected_notification, TextExtractor},
+    text_replacer, window_manager,
 };
 
 type ChangeHandler = Box<dyn Fn(bool) + Send + Sync + 'static>;
 
+// Result of hotkeys working with selected text
+// It's decided apart from windows and notifications, so handlers can be tested with fake extraction strategies
+#[derive(Debug, PartialEq)]
+enum SelectionAction {
+    ShowTranslation { show_definition: bool },
+    PlayText,
+    NotifyNoTextSelected,
+}
+
 #[derive(Clone)]
 pub struct ShortcutsManager {
     app: AppHandle,
@@ -113,19 +124,41 @@ impl ShortcutsManager {
         }
 
         self.register_shortcut(&settings.translate, "Translate Text", |app| {
-            Self::handle_translate_command(app, false);
+            Self::handle_selection_hotkey(app, |text_extractor, settings| {
+                Self::translate_selected_text(text_extractor, settings, false)
+            });
         });
 
         self.register_shortcut(&settings.show_definition, "Show Definition", |app| {
-            Self::handle_translate_command(app, true);
+            Self::handle_selection_hotkey(app, |text_extractor, settings| {
+                Self::translate_selected_text(text_extractor, settings, true)
+            });
+        });
+
+        self.register_shortcut(
+            &settings.translate_and_replace,
+            "Translate and Replace",
+            |app| {
+                text_replacer::translate_and_replace(app);
+            },
+        );
+
+        self.register_shortcut(&settings.copy_translation, "Copy Translation", |app| {
+            quick_copy::copy_translation(app);
         });
 
         self.register_shortcut(&settings.play_text, "Play Text", |app| {
-            app.state::<TextExtractor>().copy_selected_text();
-            let (window, _) = window_manager::get_or_create_translation_window(app, true);
-            app.state::<EventsManager>().emit_play_text_command(&window);
+            Self::handle_selection_hotkey(app, Self::play_selected_text);
         });
 
+        self.register_shortcut(
+            &settings.toggle_clipboard_watch,
+            "Toggle Clipboard Watch",
+            |app| {
+                app.state::<ClipboardWatcher>().toggle();
+            },
+        );
+
         self.register_shortcut(&settings.input_text, "Show Input", |app| {
             let window = window_manager::show_translation_window(app);
             app.state::<EventsManager>()
@@ -133,11 +166,55 @@ impl ShortcutsManager {
         });
     }
 
-    fn handle_translate_command(app: &AppHandle, show_definition: bool) {
-        app.state::<TextExtractor>().copy_selected_text();
-        let window = window_manager::show_translation_window(app);
-        app.state::<EventsManager>()
-            .emit_translate_text_command(&window, show_definition)
+    fn handle_selection_hotkey<F>(app: &AppHandle, handler: F)
+    where
+        F: FnOnce(&TextExtractor, &Settings) -> Result<SelectionAction, Box<dyn Error>>,
+    {
+        let settings = app.state::<SettingsManager>().read_settings();
+        let action = match handler(&app.state::<TextExtractor>(), &settings) {
+            Ok(action) => action,
+            Err(err) => {
+                show_error_notification(app, err, "Error extracting selected text");
+                return;
+            }
+        };
+
+        match action {
+            SelectionAction::ShowTranslation { show_definition } => {
+                let window = window_manager::show_translation_window(app);
+                app.state::<EventsManager>()
+                    .emit_translate_text_command(&window, show_definition)
+            }
+            SelectionAction::PlayText => {
+                let (window, _) = window_manager::get_or_create_translation_window(app, true);
+                app.state::<EventsManager>().emit_play_text_command(&window);
+            }
+            SelectionAction::NotifyNoTextSelected => show_no_text_selected_notification(app),
+        }
+    }
+
+    // Translation window tells that nothing is selected itself
+    fn translate_selected_text(
+        text_extractor: &TextExtractor,
+        settings: &Settings,
+        show_definition: bool,
+    ) -> Result<SelectionAction, Box<dyn Error>> {
+        text_extractor.copy_selected_text(settings)?;
+        Ok(SelectionAction::ShowTranslation { show_definition })
+    }
+
+    // Window stays hidden while text is played, so it can't show that nothing is selected
+    fn play_selected_text(
+        text_extractor: &TextExtractor,
+        settings: &Settings,
+    ) -> Result<SelectionAction, Box<dyn Error>> {
+        text_extractor.copy_selected_text(settings)?;
+
+        if text_extractor.text_from_clipboard().is_empty() {
+            Ok(SelectionAction::NotifyNoTextSelected)
+        } else {
+            Ok(SelectionAction::PlayText)
+        }
     }
 
     fn handle_toggle_suspend_shortcut(&self) {
@@ -210,8 +287,11 @@ impl ShortcutsManager {
             self.app
                 .global_shortcut()
                 .on_shortcut(shortcut.clone(), move |app, _shortcut, event| {
+                    // Handlers extract selected text (possibly with OCR), so they're run off the main thread
                     if event.state == ShortcutState::Pressed {
-                        handler_clone(&app);
+                        let app = app.clone();
+                        let handler = handler_clone.clone();
+                        tauri::async_runtime::spawn_blocking(move || handler(&app));
                     }
                 })
                 .map(|_| registered_shortcuts.push(shortcut.clone()))
@@ -258,7 +338,86 @@ impl ShortcutsManager {
         return old_settings.translate != new_settings.translate
             || old_settings.input_text != new_settings.input_text
             || old_settings.show_definition != new_settings.show_definition
+            || old_settings.translate_and_replace != new_settings.translate_and_replace
+            || old_settings.copy_translation != new_settings.copy_translation
             || old_settings.toggle_suspend != new_settings.toggle_suspend
+            || old_settings.toggle_clipboard_watch != new_settings.toggle_clipboard_watch
             || old_settings.play_text != new_settings.play_text;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{settings::ExtractionStrategyKind, text_extractor::ExtractionStrategy};
+
+    struct FakeStrategy {
+        result: Result<&'static str, &'static str>,
+    }
+
+    impl ExtractionStrategy for FakeStrategy {
+        fn kind(&self) -> ExtractionStrategyKind {
+            ExtractionStrategyKind::Clipboard
+        }
+
+        fn extract_text(&self, _settings: &Settings) -> Result<String, Box<dyn Error>> {
+            self.result
+                .map(|text| text.to_string())
+                .map_err(|err| err.into())
+        }
+    }
+
+    fn text_extractor(result: Result<&'static str, &'static str>) -> TextExtractor {
+        TextExtractor::with_strategies(vec![Box::new(FakeStrategy { result })])
+    }
+
+    fn settings() -> Settings {
+        let mut settings = SettingsManager::read_default_settings();
+        settings.core.extraction_strategies = vec![ExtractionStrategyKind::Clipboard];
+        settings
+    }
+
+    #[test]
+    fn plays_selected_text() {
+        let text_extractor = text_extractor(Ok("selected text"));
+
+        let action = ShortcutsManager::play_selected_text(&text_extractor, &settings()).unwrap();
+
+        assert_eq!(action, SelectionAction::PlayText);
+        assert_eq!(text_extractor.text_from_clipboard(), "selected text");
+    }
+
+    #[test]
+    fn notifies_when_nothing_is_selected_for_playing() {
+        let text_extractor = text_extractor(Ok(" "));
+
+        let action = ShortcutsManager::play_selected_text(&text_extractor, &settings()).unwrap();
+
+        assert_eq!(action, SelectionAction::NotifyNoTextSelected);
+    }
+
+    #[test]
+    fn shows_translation_even_when_nothing_is_selected() {
+        let text_extractor = text_extractor(Ok(""));
+
+        let action =
+            ShortcutsManager::translate_selected_text(&text_extractor, &settings(), true).unwrap();
+
+        assert_eq!(
+            action,
+            SelectionAction::ShowTranslation {
+                show_definition: true
+            }
+        );
+    }
+
+    #[test]
+    fn returns_extraction_error() {
+        let text_extractor = text_extractor(Err("copy failed"));
+
+        let err = ShortcutsManager::play_selected_text(&text_extractor, &settings()).unwrap_err();
+
+        assert_eq!(err.to_string(), "copy failed");
+        assert_eq!(text_extractor.text_from_clipboard(), "");
+    }
+}
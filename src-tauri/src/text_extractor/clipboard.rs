@@ -0,0 +1,53 @@
+use std::error::Error;
+
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use super::ExtractionStrategy;
+use crate::{
+    ocr,
+    settings::{ExtractionStrategyKind, Settings},
+};
+
+// Takes whatever is in clipboard, so it's also used to read the result of synthetic copy
+pub struct ClipboardStrategy {
+    app: AppHandle,
+}
+
+impl ExtractionStrategy for ClipboardStrategy {
+    fn kind(&self) -> ExtractionStrategyKind {
+        ExtractionStrategyKind::Clipboard
+    }
+
+    fn extract_text(&self, settings: &Settings) -> Result<String, Box<dyn Error>> {
+        let content = self.app.clipboard().read_text();
+
+        // Clipboard without text might contain an image (e.g. screenshot), so text is recognized from it
+        match content {
+            Ok(text) if !text.trim().is_empty() => Ok(text),
+            _ => self.recognize_text_from_image(settings),
+        }
+    }
+}
+
+impl ClipboardStrategy {
+    pub fn new(app: &AppHandle) -> Self {
+        Self { app: app.clone() }
+    }
+
+    fn recognize_text_from_image(&self, settings: &Settings) -> Result<String, Box<dyn Error>> {
+        if !settings.core.ocr.enabled {
+            return Ok(String::new());
+        }
+
+        let Ok(image) = self.app.clipboard().read_image() else {
+            return Ok(String::new());
+        };
+
+        ocr::recognize_text(
+            &image,
+            &settings.core.ocr,
+            &settings.translation.source_language,
+        )
+    }
+}
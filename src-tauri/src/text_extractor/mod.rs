@@ -0,0 +1,324 @@
+use log::{debug, info, warn};
+use std::{error::Error, sync::Mutex};
+use tauri::AppHandle;
+
+use crate::notifications::show_notification;
+use crate::settings::{ExtractionStrategyKind, Settings};
+use crate::text_normalizer;
+
+pub use source_context::SourceContext;
+
+use clipboard::ClipboardStrategy;
+#[cfg(target_os = "linux")]
+use primary_selection::PrimarySelectionStrategy;
+#[cfg(windows)]
+use synthetic_copy::SyntheticCopyStrategy;
+#[cfg(windows)]
+use ui_automation::UiAutomationStrategy;
+
+mod clipboard;
+#[cfg(target_os = "linux")]
+mod primary_selection;
+mod source_context;
+#[cfg(windows)]
+mod synthetic_copy;
+#[cfg(windows)]
+mod ui_automation;
+
+// Way of getting text selected in another app
+// Strategies which need app (e.g. for clipboard access) keep it themselves, so they can be run without it in tests
+pub trait ExtractionStrategy: Send + Sync {
+    fn kind(&self) -> ExtractionStrategyKind;
+
+    // Empty text means that strategy didn't find selected text, so the next one should be tried
+    fn extract_text(&self, settings: &Settings) -> Result<String, Box<dyn Error>>;
+}
+
+pub struct TextExtractor {
+    text_from_clipboard: Mutex<String>,
+    source_context: Mutex<Option<SourceContext>>,
+    strategies: Vec<Box<dyn ExtractionStrategy>>,
+}
+
+impl TextExtractor {
+    pub fn new(app: &AppHandle) -> Self {
+        Self::with_strategies(Self::platform_strategies(app))
+    }
+
+    // Strategies can be replaced, e.g. with a fake one, so hotkey handlers can run without real selection
+    pub fn with_strategies(strategies: Vec<Box<dyn ExtractionStrategy>>) -> Self {
+        Self {
+            text_from_clipboard: Mutex::new(String::new()),
+            source_context: Mutex::new(None),
+            strategies,
+        }
+    }
+
+    pub fn text_from_clipboard(&self) -> String {
+        self.text_from_clipboard.lock().unwrap().clone()
+    }
+
+    pub fn source_context(&self) -> Option<SourceContext> {
+        self.source_context.lock().unwrap().clone()
+    }
+
+    // Text is cleared when extraction fails, so previous selection isn't picked up instead
+    pub fn copy_selected_text(&self, settings: &Settings) -> Result<(), Box<dyn Error>> {
+        let result = extract_selected_text(&self.strategies, settings);
+
+        // Context is captured after extraction, so it doesn't delay synthetic copy
+        // Source app is still in foreground, since translation window isn't shown yet
+        *self.source_context.lock().unwrap() = source_context::capture();
+        self.set_text(result.as_deref().unwrap_or_default(), settings);
+
+        result.map(|_| ())
+    }
+
+    pub fn extract_text_from_clipboard(&self, settings: &Settings) -> Result<(), Box<dyn Error>> {
+        // Clipboard content might come from anywhere, so there is no context for it
+        *self.source_context.lock().unwrap() = None;
+
+        let result = self
+            .strategies
+            .iter()
+            .find(|strategy| strategy.kind() == ExtractionStrategyKind::Clipboard)
+            .ok_or("Clipboard strategy isn't available")?
+            .extract_text(settings);
+        self.set_text(result.as_deref().unwrap_or_default(), settings);
+
+        result.map(|_| ())
+    }
+
+    // Text is normalized once extracted, so it's ready for all consumers (e.g. frontend or quick copy)
+    fn set_text(&self, text: &str, settings: &Settings) {
+        *self.text_from_clipboard.lock().unwrap() =
+            text_normalizer::normalize(text, &settings.core.text_normalization);
+    }
+
+    fn platform_strategies(app: &AppHandle) -> Vec<Box<dyn ExtractionStrategy>> {
+        vec![
+            #[cfg(windows)]
+            Box::new(SyntheticCopyStrategy::new(app)),
+            #[cfg(windows)]
+            Box::new(UiAutomationStrategy),
+            #[cfg(target_os = "linux")]
+            Box::new(PrimarySelectionStrategy),
+            Box::new(ClipboardStrategy::new(app)),
+        ]
+    }
+}
+
+// Hotkeys which don't show translation window report missing selection with a notification
+// Translation window shows the same message itself
+pub fn show_no_text_selected_notification(app: &AppHandle) {
+    show_notification(
+        app,
+        "No text data selected",
+        Some("Select text in another app before using the hotkey."),
+    );
+}
+
+// Strategies are tried in the order from settings, the first one which returns text wins
+// Error is returned only if none of the strategies found selected text
+fn extract_selected_text(
+    strategies: &[Box<dyn ExtractionStrategy>],
+    settings: &Settings,
+) -> Result<String, Box<dyn Error>> {
+    let mut last_error = None;
+
+    for strategy_kind in &settings.core.extraction_strategies {
+        let Some(strategy) = strategies
+            .iter()
+            .find(|strategy| strategy.kind() == *strategy_kind)
+        else {
+            debug!("Text extraction strategy {strategy_kind:?} isn't supported on this platform.");
+            continue;
+        };
+
+        match strategy.extract_text(settings) {
+            Ok(text) if !text.trim().is_empty() => return Ok(text),
+            Ok(_) => info!("No selected text is found with {strategy_kind:?} strategy."),
+            Err(err) => {
+                warn!(
+                    "Error extracting text with {strategy_kind:?} strategy. {}",
+                    err
+                );
+                last_error = Some(err);
+            }
+        }
+    }
+
+    match last_error {
+        Some(err) => Err(err),
+        None => Ok(String::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::settings::SettingsManager;
+
+    type Calls = Arc<Mutex<Vec<ExtractionStrategyKind>>>;
+
+    struct FakeStrategy {
+        kind: ExtractionStrategyKind,
+        result: Result<&'static str, &'static str>,
+        calls: Calls,
+    }
+
+    impl ExtractionStrategy for FakeStrategy {
+        fn kind(&self) -> ExtractionStrategyKind {
+            self.kind
+        }
+
+        fn extract_text(&self, _settings: &Settings) -> Result<String, Box<dyn Error>> {
+            self.calls.lock().unwrap().push(self.kind);
+            self.result
+                .map(|text| text.to_string())
+                .map_err(|err| err.into())
+        }
+    }
+
+    fn strategies(
+        results: Vec<(ExtractionStrategyKind, Result<&'static str, &'static str>)>,
+        calls: &Calls,
+    ) -> Vec<Box<dyn ExtractionStrategy>> {
+        results
+            .into_iter()
+            .map(|(kind, result)| {
+                Box::new(FakeStrategy {
+                    kind,
+                    result,
+                    calls: calls.clone(),
+                }) as Box<dyn ExtractionStrategy>
+            })
+            .collect()
+    }
+
+    fn settings(strategy_kinds: Vec<ExtractionStrategyKind>) -> Settings {
+        let mut settings = SettingsManager::read_default_settings();
+        settings.core.extraction_strategies = strategy_kinds;
+        settings
+    }
+
+    #[test]
+    fn falls_back_in_settings_order() {
+        let calls = Calls::default();
+        let strategies = strategies(
+            vec![
+                (ExtractionStrategyKind::Clipboard, Ok("from clipboard")),
+                (ExtractionStrategyKind::SyntheticCopy, Ok("  \n")),
+                (ExtractionStrategyKind::UiAutomation, Err("no pattern")),
+            ],
+            &calls,
+        );
+        let settings = settings(vec![
+            ExtractionStrategyKind::SyntheticCopy,
+            ExtractionStrategyKind::UiAutomation,
+            ExtractionStrategyKind::Clipboard,
+        ]);
+
+        let text = extract_selected_text(&strategies, &settings).unwrap();
+
+        assert_eq!(text, "from clipboard");
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                ExtractionStrategyKind::SyntheticCopy,
+                ExtractionStrategyKind::UiAutomation,
+                ExtractionStrategyKind::Clipboard,
+            ]
+        );
+    }
+
+    #[test]
+    fn stops_at_first_found_text() {
+        let calls = Calls::default();
+        let strategies = strategies(
+            vec![
+                (ExtractionStrategyKind::SyntheticCopy, Ok("selected")),
+                (ExtractionStrategyKind::Clipboard, Ok("from clipboard")),
+            ],
+            &calls,
+        );
+        let settings = settings(vec![
+            ExtractionStrategyKind::SyntheticCopy,
+            ExtractionStrategyKind::Clipboard,
+        ]);
+
+        let text = extract_selected_text(&strategies, &settings).unwrap();
+
+        assert_eq!(text, "selected");
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![ExtractionStrategyKind::SyntheticCopy]
+        );
+    }
+
+    #[test]
+    fn skips_unsupported_and_disabled_strategies() {
+        let calls = Calls::default();
+        let strategies = strategies(
+            vec![
+                (ExtractionStrategyKind::UiAutomation, Ok("not enabled")),
+                (ExtractionStrategyKind::Clipboard, Ok("from clipboard")),
+            ],
+            &calls,
+        );
+        let settings = settings(vec![
+            ExtractionStrategyKind::PrimarySelection,
+            ExtractionStrategyKind::Clipboard,
+        ]);
+
+        let text = extract_selected_text(&strategies, &settings).unwrap();
+
+        assert_eq!(text, "from clipboard");
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![ExtractionStrategyKind::Clipboard]
+        );
+    }
+
+    #[test]
+    fn returns_empty_text_when_nothing_is_found() {
+        let calls = Calls::default();
+        let strategies = strategies(
+            vec![
+                (ExtractionStrategyKind::SyntheticCopy, Ok("")),
+                (ExtractionStrategyKind::Clipboard, Ok(" ")),
+            ],
+            &calls,
+        );
+        let settings = settings(vec![
+            ExtractionStrategyKind::SyntheticCopy,
+            ExtractionStrategyKind::Clipboard,
+        ]);
+
+        let text = extract_selected_text(&strategies, &settings).unwrap();
+
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn returns_last_error_when_nothing_is_found() {
+        let calls = Calls::default();
+        let strategies = strategies(
+            vec![
+                (ExtractionStrategyKind::SyntheticCopy, Err("copy failed")),
+                (ExtractionStrategyKind::Clipboard, Err("clipboard failed")),
+            ],
+            &calls,
+        );
+        let settings = settings(vec![
+            ExtractionStrategyKind::SyntheticCopy,
+            ExtractionStrategyKind::Clipboard,
+        ]);
+
+        let err = extract_selected_text(&strategies, &settings).unwrap_err();
+
+        assert_eq!(err.to_string(), "clipboard failed");
+    }
+}
@@ -0,0 +1,61 @@
+use std::error::Error;
+
+use arboard::{Clipboard, GetExtLinux, LinuxClipboardKind};
+
+use super::ExtractionStrategy;
+use crate::settings::{ExtractionStrategyKind, Settings};
+
+// On Linux selected text is available as PRIMARY selection, so it can be read directly,
+// without synthetic key presses and without touching the clipboard
+// X11 selection is used, unless compositor supports Wayland data-control protocol
+pub struct PrimarySelectionStrategy;
+
+impl ExtractionStrategy for PrimarySelectionStrategy {
+    fn kind(&self) -> ExtractionStrategyKind {
+        ExtractionStrategyKind::PrimarySelection
+    }
+
+    fn extract_text(&self, _settings: &Settings) -> Result<String, Box<dyn Error>> {
+        let mut clipboard = Clipboard::new()?;
+        let result = clipboard
+            .get()
+            .clipboard(LinuxClipboardKind::Primary)
+            .text();
+
+        match result {
+            Ok(text) => Ok(text),
+            // Nothing is selected or selection isn't a text
+            Err(arboard::Error::ContentNotAvailable) => Ok(String::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arboard::SetExtLinux;
+
+    use super::*;
+    use crate::settings::SettingsManager;
+
+    // Needs a running display, e.g. `xvfb-run cargo test -- --ignored`
+    #[test]
+    #[ignore]
+    fn reads_primary_selection() {
+        let text = "selected text";
+
+        // Selection is served only while its owner is alive, so clipboard is kept till the end of the test
+        let mut clipboard = Clipboard::new().unwrap();
+        clipboard
+            .set()
+            .clipboard(LinuxClipboardKind::Primary)
+            .text(text)
+            .unwrap();
+
+        let settings = SettingsManager::read_default_settings();
+        assert_eq!(
+            PrimarySelectionStrategy.extract_text(&settings).unwrap(),
+            text
+        );
+    }
+}
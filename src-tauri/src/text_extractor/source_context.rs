@@ -0,0 +1,247 @@
+use serde::Serialize;
+
+// Application the text has been selected in, it's stored with translation,
+// so history can be filtered by the place where word was encountered
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceContext {
+    pub process_name: Option<String>,
+    pub window_title: Option<String>,
+    // Available only for browsers
+    pub url: Option<String>,
+}
+
+#[cfg(windows)]
+pub use windows_context::capture;
+
+#[cfg(target_os = "linux")]
+pub use x11_context::capture;
+
+#[cfg(not(any(windows, target_os = "linux")))]
+pub fn capture() -> Option<SourceContext> {
+    None
+}
+
+#[cfg(windows)]
+mod windows_context {
+    use std::{error::Error, path::Path};
+
+    use log::debug;
+    use windows::{
+        core::{PWSTR, VARIANT},
+        Win32::{
+            Foundation::{CloseHandle, HWND},
+            System::{
+                Com::{CoCreateInstance, CLSCTX_INPROC_SERVER},
+                Threading::{
+                    OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+                    PROCESS_QUERY_LIMITED_INFORMATION,
+                },
+            },
+            UI::{
+                Accessibility::{
+                    CUIAutomation, IUIAutomation, IUIAutomationValuePattern, TreeScope_Descendants,
+                    UIA_AutomationIdPropertyId, UIA_ClassNamePropertyId, UIA_ValuePatternId,
+                    UIA_PROPERTY_ID,
+                },
+                WindowsAndMessaging::{
+                    GetForegroundWindow, GetWindowTextLengthW, GetWindowTextW,
+                    GetWindowThreadProcessId,
+                },
+            },
+        },
+    };
+
+    use super::SourceContext;
+    use crate::text_extractor::ui_automation::ComInitialization;
+
+    // Address bar is looked up by its known property, since position of edit controls differs between browsers
+    // and a page might contain edit controls as well
+    type AddressBarProperty = (UIA_PROPERTY_ID, &'static str);
+
+    const CHROMIUM_ADDRESS_BAR: AddressBarProperty = (UIA_ClassNamePropertyId, "OmniboxViewViews");
+    const FIREFOX_ADDRESS_BAR: AddressBarProperty = (UIA_AutomationIdPropertyId, "urlbar-input");
+
+    const BROWSER_ADDRESS_BARS: [(&str, AddressBarProperty); 6] = [
+        ("chrome.exe", CHROMIUM_ADDRESS_BAR),
+        ("msedge.exe", CHROMIUM_ADDRESS_BAR),
+        ("brave.exe", CHROMIUM_ADDRESS_BAR),
+        ("opera.exe", CHROMIUM_ADDRESS_BAR),
+        ("vivaldi.exe", CHROMIUM_ADDRESS_BAR),
+        ("firefox.exe", FIREFOX_ADDRESS_BAR),
+    ];
+
+    pub fn capture() -> Option<SourceContext> {
+        let window = unsafe { GetForegroundWindow() };
+        if window.0 == 0 {
+            return None;
+        }
+
+        let process_name = get_process_name(window);
+        let url = process_name
+            .as_deref()
+            .and_then(get_address_bar_property)
+            .and_then(|address_bar_property| {
+                get_browser_url(window, address_bar_property)
+                    .inspect_err(|err| debug!("Unable to get browser URL. {}", err))
+                    .ok()
+                    .flatten()
+            });
+
+        Some(SourceContext {
+            process_name,
+            window_title: get_window_title(window),
+            url,
+        })
+    }
+
+    fn get_window_title(window: HWND) -> Option<String> {
+        unsafe {
+            let length = GetWindowTextLengthW(window);
+            if length == 0 {
+                return None;
+            }
+
+            let mut buffer = vec![0u16; length as usize + 1];
+            let copied_length = GetWindowTextW(window, &mut buffer);
+
+            Some(String::from_utf16_lossy(&buffer[..copied_length as usize]))
+        }
+    }
+
+    fn get_process_name(window: HWND) -> Option<String> {
+        unsafe {
+            let mut process_id = 0;
+            GetWindowThreadProcessId(window, Some(&mut process_id));
+
+            let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).ok()?;
+
+            let mut buffer = [0u16; 1024];
+            let mut size = buffer.len() as u32;
+            let result = QueryFullProcessImageNameW(
+                process,
+                PROCESS_NAME_WIN32,
+                PWSTR(buffer.as_mut_ptr()),
+                &mut size,
+            );
+            CloseHandle(process).ok();
+            result.ok()?;
+
+            let path = String::from_utf16_lossy(&buffer[..size as usize]);
+            Path::new(&path)
+                .file_name()
+                .map(|file_name| file_name.to_string_lossy().to_string())
+        }
+    }
+
+    fn get_address_bar_property(process_name: &str) -> Option<AddressBarProperty> {
+        BROWSER_ADDRESS_BARS
+            .iter()
+            .find(|(browser, _)| browser.eq_ignore_ascii_case(process_name))
+            .map(|(_, address_bar_property)| *address_bar_property)
+    }
+
+    fn get_browser_url(
+        window: HWND,
+        (property_id, property_value): AddressBarProperty,
+    ) -> Result<Option<String>, Box<dyn Error>> {
+        let _com = ComInitialization::initialize();
+
+        unsafe {
+            let automation: IUIAutomation =
+                CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER)?;
+            let window_element = automation.ElementFromHandle(window)?;
+
+            let condition =
+                automation.CreatePropertyCondition(property_id, &VARIANT::from(property_value))?;
+            let address_bar = window_element.FindFirst(TreeScope_Descendants, &condition)?;
+            let value_pattern =
+                address_bar.GetCurrentPatternAs::<IUIAutomationValuePattern>(UIA_ValuePatternId)?;
+
+            let url = value_pattern.CurrentValue()?.to_string();
+            Ok(Some(url).filter(|url| !url.is_empty()))
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod x11_context {
+    use std::{error::Error, fs};
+
+    use log::debug;
+    use x11rb::{
+        connection::Connection,
+        protocol::xproto::{Atom, AtomEnum, ConnectionExt, Window},
+        rust_connection::RustConnection,
+    };
+
+    use super::SourceContext;
+
+    // Active window is taken from EWMH properties, so it's available only on X11 (and for XWayland windows)
+    pub fn capture() -> Option<SourceContext> {
+        capture_active_window()
+            .inspect_err(|err| debug!("Unable to get active window. {}", err))
+            .ok()
+            .flatten()
+    }
+
+    fn capture_active_window() -> Result<Option<SourceContext>, Box<dyn Error>> {
+        let (connection, screen_number) = x11rb::connect(None)?;
+        let root = connection.setup().roots[screen_number].root;
+
+        let active_window_atom = get_atom(&connection, b"_NET_ACTIVE_WINDOW")?;
+        let active_window = connection
+            .get_property(false, root, active_window_atom, AtomEnum::WINDOW, 0, 1)?
+            .reply()?
+            .value32()
+            .and_then(|mut values| values.next());
+
+        let Some(window) = active_window.filter(|window| *window != 0) else {
+            return Ok(None);
+        };
+
+        Ok(Some(SourceContext {
+            process_name: get_process_name(&connection, window)?,
+            window_title: get_window_title(&connection, window)?,
+            url: None,
+        }))
+    }
+
+    fn get_window_title(
+        connection: &RustConnection,
+        window: Window,
+    ) -> Result<Option<String>, Box<dyn Error>> {
+        let name_atom = get_atom(connection, b"_NET_WM_NAME")?;
+        let utf8_string_atom = get_atom(connection, b"UTF8_STRING")?;
+
+        let title = connection
+            .get_property(false, window, name_atom, utf8_string_atom, 0, u32::MAX)?
+            .reply()?
+            .value;
+
+        Ok(Some(String::from_utf8_lossy(&title).to_string()).filter(|title| !title.is_empty()))
+    }
+
+    fn get_process_name(
+        connection: &RustConnection,
+        window: Window,
+    ) -> Result<Option<String>, Box<dyn Error>> {
+        let pid_atom = get_atom(connection, b"_NET_WM_PID")?;
+
+        let pid = connection
+            .get_property(false, window, pid_atom, AtomEnum::CARDINAL, 0, 1)?
+            .reply()?
+            .value32()
+            .and_then(|mut values| values.next());
+
+        Ok(pid.and_then(|pid| {
+            fs::read_to_string(format!("/proc/{pid}/comm"))
+                .ok()
+                .map(|process_name| process_name.trim().to_string())
+        }))
+    }
+
+    fn get_atom(connection: &RustConnection, name: &[u8]) -> Result<Atom, Box<dyn Error>> {
+        Ok(connection.intern_atom(false, name)?.reply()?.atom)
+    }
+}
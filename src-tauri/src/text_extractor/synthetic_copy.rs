@@ -0,0 +1,102 @@
+use std::{
+    error::Error,
+    thread,
+    time::{Duration, Instant},
+};
+
+use log::{info, warn};
+use tauri::{AppHandle, Manager};
+use windows::Win32::System::DataExchange::GetClipboardSequenceNumber;
+
+use super::{clipboard::ClipboardStrategy, ExtractionStrategy};
+use crate::{
+    clipboard_snapshot::ClipboardSnapshot,
+    clipboard_watcher::ClipboardWatcher,
+    input_injection,
+    settings::{CoreSettings, ExtractionStrategyKind, Settings},
+};
+
+// Sends Ctrl + C to the active app and reads copied text from clipboard
+pub struct SyntheticCopyStrategy {
+    app: AppHandle,
+    clipboard_strategy: ClipboardStrategy,
+}
+
+impl ExtractionStrategy for SyntheticCopyStrategy {
+    fn kind(&self) -> ExtractionStrategyKind {
+        ExtractionStrategyKind::SyntheticCopy
+    }
+
+    fn extract_text(&self, settings: &Settings) -> Result<String, Box<dyn Error>> {
+        let _own_write = self.app.state::<ClipboardWatcher>().begin_own_write();
+
+        // Synthetic copy overwrites user's clipboard, so it's restored once selected text is read
+        let clipboard_snapshot = Self::take_clipboard_snapshot(&settings.core);
+
+        let sequence_number = Self::clipboard_sequence_number();
+
+        input_injection::send_copy_command()?;
+
+        // Copy delay is used as a timeout, text is read as soon as the target app updates clipboard
+        let timeout = Duration::from_millis(settings.core.copy_delay_milliseconds);
+        let result = if Self::wait_for_clipboard_change(sequence_number, timeout) {
+            self.clipboard_strategy.extract_text(settings)
+        } else {
+            // Clipboard is left intact when there is no selection, so its content is stale
+            // Empty text is reported to the user by the hotkey, same as any other missing selection
+            info!("Clipboard hasn't been changed by copy command, no text is selected.");
+            Ok(String::new())
+        };
+
+        Self::restore_clipboard_snapshot(clipboard_snapshot);
+
+        result
+    }
+}
+
+impl SyntheticCopyStrategy {
+    pub fn new(app: &AppHandle) -> Self {
+        Self {
+            app: app.clone(),
+            clipboard_strategy: ClipboardStrategy::new(app),
+        }
+    }
+
+    fn clipboard_sequence_number() -> u32 {
+        unsafe { GetClipboardSequenceNumber() }
+    }
+
+    // Sequence number is incremented by the system on every clipboard change
+    fn wait_for_clipboard_change(sequence_number: u32, timeout: Duration) -> bool {
+        const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+        let start = Instant::now();
+        while start.elapsed() < timeout {
+            if Self::clipboard_sequence_number() != sequence_number {
+                return true;
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+
+        Self::clipboard_sequence_number() != sequence_number
+    }
+
+    fn take_clipboard_snapshot(settings: &CoreSettings) -> Option<ClipboardSnapshot> {
+        if !settings.preserve_clipboard {
+            return None;
+        }
+
+        ClipboardSnapshot::take()
+            .inspect_err(|err| warn!("Unable to take clipboard snapshot. {}", err))
+            .ok()
+    }
+
+    fn restore_clipboard_snapshot(clipboard_snapshot: Option<ClipboardSnapshot>) {
+        if let Some(clipboard_snapshot) = clipboard_snapshot {
+            clipboard_snapshot
+                .restore()
+                .unwrap_or_else(|err| warn!("Unable to restore clipboard. {}", err));
+        }
+    }
+}
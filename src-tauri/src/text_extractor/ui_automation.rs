@@ -0,0 +1,73 @@
+use std::error::Error;
+
+use windows::Win32::{
+    System::Com::{
+        CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_INPROC_SERVER,
+        COINIT_APARTMENTTHREADED,
+    },
+    UI::Accessibility::{
+        CUIAutomation, IUIAutomation, IUIAutomationTextPattern, UIA_TextPatternId,
+    },
+};
+
+use super::ExtractionStrategy;
+use crate::settings::{ExtractionStrategyKind, Settings};
+
+// Reads selection of the focused control via UI Automation, so neither keyboard nor clipboard is used
+// Works only for controls exposing text pattern (e.g. standard edit controls, Word, browsers with accessibility enabled)
+pub struct UiAutomationStrategy;
+
+impl ExtractionStrategy for UiAutomationStrategy {
+    fn kind(&self) -> ExtractionStrategyKind {
+        ExtractionStrategyKind::UiAutomation
+    }
+
+    fn extract_text(&self, _settings: &Settings) -> Result<String, Box<dyn Error>> {
+        let _com = ComInitialization::initialize();
+
+        unsafe {
+            let automation: IUIAutomation =
+                CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER)?;
+            let focused_element = automation.GetFocusedElement()?;
+
+            // Focused control doesn't support text pattern, so there is no selection to read
+            let Ok(text_pattern) =
+                focused_element.GetCurrentPatternAs::<IUIAutomationTextPattern>(UIA_TextPatternId)
+            else {
+                return Ok(String::new());
+            };
+
+            let selection = text_pattern.GetSelection()?;
+            let mut text = String::new();
+            for index in 0..selection.Length()? {
+                let range = selection.GetElement(index)?;
+                text.push_str(&range.GetText(-1)?.to_string());
+            }
+
+            Ok(text)
+        }
+    }
+}
+
+// COM is usually initialized on the main thread already, in this case initialization just increments the counter
+// Each successful initialization has to be balanced with uninitialization
+pub(super) struct ComInitialization {
+    is_initialized: bool,
+}
+
+impl ComInitialization {
+    pub(super) fn initialize() -> Self {
+        let result = unsafe { CoInitializeEx(None, COINIT_APARTMENTTHREADED) };
+        Self {
+            is_initialized: result.is_ok(),
+        }
+    }
+}
+
+impl Drop for ComInitialization {
+    fn drop(&mut self) {
+        if self.is_initialized {
+            unsafe { CoUninitialize() };
+        }
+    }
+}
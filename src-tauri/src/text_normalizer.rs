@@ -0,0 +1,381 @@
+use crate::settings::TextNormalizationSettings;
+
+const LIST_MARKERS: [char; 9] = ['•', '◦', '▪', '▫', '‣', '·', '-', '*', '+'];
+
+// Cleans up text copied from PDFs, emails and code, so it's translated as a regular sentence
+// Steps don't depend on clipboard or app state and are applied in a fixed order
+pub fn normalize(text: &str, settings: &TextNormalizationSettings) -> String {
+    let mut text = text.replace("\r\n", "\n");
+
+    if settings.normalize_characters {
+        text = normalize_characters(&text);
+    }
+
+    // Markers are stripped per line, so it has to be done before lines are joined
+    if settings.strip_list_markers {
+        text = strip_list_markers(&text);
+    }
+
+    if settings.join_hyphenated_words {
+        text = join_hyphenated_words(&text);
+    }
+
+    if settings.join_wrapped_lines {
+        text = join_wrapped_lines(&text);
+    }
+
+    if settings.split_identifiers {
+        text = split_identifiers(&text);
+    }
+
+    truncate(text.trim(), settings.max_length)
+}
+
+fn normalize_characters(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        match char {
+            'ﬀ' => result.push_str("ff"),
+            'ﬁ' => result.push_str("fi"),
+            'ﬂ' => result.push_str("fl"),
+            'ﬃ' => result.push_str("ffi"),
+            'ﬄ' => result.push_str("ffl"),
+            'ﬅ' | 'ﬆ' => result.push_str("st"),
+            '‘' | '’' | '‚' | '‛' | '′' => result.push('\''),
+            '“' | '”' | '„' | '‟' | '″' => result.push('"'),
+            '\u{00A0}' | '\u{2007}' | '\u{202F}' => result.push(' '),
+            // Soft hyphens and zero-width characters are invisible, but break words for translation
+            '\u{00AD}' | '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{FEFF}' => {}
+            char => result.push(char),
+        }
+    }
+
+    result
+}
+
+// Removes email quote markers (`> `) and bullets from the start of each line
+// Indentation is kept, so code and poetry aren't changed when there are no markers
+fn strip_list_markers(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let content = line.trim_start();
+            let indentation = &line[..line.len() - content.len()];
+
+            let mut content = content;
+            while let Some(unquoted_content) = content.strip_prefix('>') {
+                content = unquoted_content.trim_start();
+            }
+
+            // Marker has to be followed by whitespace, so "-5 degrees" or "*emphasis*" is kept
+            let mut chars = content.chars();
+            let is_bullet = chars
+                .next()
+                .is_some_and(|char| LIST_MARKERS.contains(&char))
+                && chars.next().is_some_and(char::is_whitespace);
+            if is_bullet {
+                content = content[content.chars().next().unwrap().len_utf8()..].trim_start();
+            }
+
+            format!("{indentation}{content}")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Joins words like "transla-\ntion", line break before capitalized word is treated as a regular one
+fn join_hyphenated_words(text: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in text.lines() {
+        if let Some(previous_line) = lines.last_mut() {
+            if is_hyphenated_line_break(previous_line, line) {
+                previous_line.truncate(previous_line.trim_end().len() - '-'.len_utf8());
+                previous_line.push_str(line.trim_start());
+                continue;
+            }
+        }
+
+        lines.push(line.to_string());
+    }
+
+    lines.join("\n")
+}
+
+fn is_hyphenated_line_break(line: &str, next_line: &str) -> bool {
+    let ends_with_hyphen = line
+        .trim_end()
+        .strip_suffix('-')
+        .is_some_and(|line| line.chars().last().is_some_and(char::is_alphabetic));
+    let continues_with_word = next_line
+        .trim_start()
+        .chars()
+        .next()
+        .is_some_and(char::is_lowercase);
+
+    ends_with_hyphen && continues_with_word
+}
+
+// Lines are joined within paragraph, paragraphs are separated by empty lines
+fn join_wrapped_lines(text: &str) -> String {
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut paragraph_lines: Vec<&str> = Vec::new();
+
+    for line in text.lines().map(str::trim) {
+        if !line.is_empty() {
+            paragraph_lines.push(line);
+        } else if !paragraph_lines.is_empty() {
+            paragraphs.push(paragraph_lines.join(" "));
+            paragraph_lines.clear();
+        }
+    }
+
+    if !paragraph_lines.is_empty() {
+        paragraphs.push(paragraph_lines.join(" "));
+    }
+
+    paragraphs.join("\n\n")
+}
+
+// Splits identifiers like "getUserName" or "user_name" into separate words
+fn split_identifiers(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut identifier = String::new();
+
+    for char in text.chars() {
+        if char.is_alphanumeric() || char == '_' {
+            identifier.push(char);
+        } else {
+            result.push_str(&split_identifier(&identifier));
+            identifier.clear();
+            result.push(char);
+        }
+    }
+    result.push_str(&split_identifier(&identifier));
+
+    result
+}
+
+fn split_identifier(identifier: &str) -> String {
+    let words: Vec<String> = identifier
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .flat_map(split_camel_case)
+        .collect();
+
+    // Regular words and leading/trailing underscores are left as is
+    if words.len() < 2 {
+        return identifier.to_string();
+    }
+
+    words
+        .into_iter()
+        .map(|word| {
+            // Acronyms keep their case
+            if word.chars().all(|char| !char.is_lowercase()) {
+                word
+            } else {
+                word.to_lowercase()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+// Word boundary is before an uppercase letter following a lowercase one ("userName"),
+// or before the last letter of an acronym followed by a lowercase one ("HTMLParser")
+fn split_camel_case(part: &str) -> Vec<String> {
+    let chars: Vec<char> = part.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for (index, &char) in chars.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|index| chars[index]);
+        let next = chars.get(index + 1);
+
+        let is_boundary = char.is_uppercase()
+            && previous.is_some_and(|previous| {
+                previous.is_lowercase()
+                    || (previous.is_uppercase() && next.is_some_and(|next| next.is_lowercase()))
+            });
+
+        if is_boundary && !word.is_empty() {
+            words.push(word);
+            word = String::new();
+        }
+        word.push(char);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+// Max length of 0 means no limit
+fn truncate(text: &str, max_length: u32) -> String {
+    if max_length == 0 {
+        return text.to_string();
+    }
+
+    text.chars()
+        .take(max_length as usize)
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> TextNormalizationSettings {
+        TextNormalizationSettings {
+            join_hyphenated_words: false,
+            join_wrapped_lines: false,
+            strip_list_markers: false,
+            normalize_characters: false,
+            split_identifiers: false,
+            max_length: 0,
+        }
+    }
+
+    #[test]
+    fn joins_hyphenated_words() {
+        assert_eq!(
+            join_hyphenated_words("automatic transla-\ntion of text"),
+            "automatic translation of text"
+        );
+        assert_eq!(join_hyphenated_words("well-\nKnown"), "well-\nKnown");
+        assert_eq!(join_hyphenated_words("range 1-\n5"), "range 1-\n5");
+    }
+
+    #[test]
+    fn joins_wrapped_lines_within_paragraphs() {
+        assert_eq!(
+            join_wrapped_lines("first line\nsecond line\n\nnext paragraph"),
+            "first line second line\n\nnext paragraph"
+        );
+    }
+
+    #[test]
+    fn collapses_whitespace_around_line_breaks() {
+        assert_eq!(
+            join_wrapped_lines("  first  \n\t second \n\n\n\n third"),
+            "first second\n\nthird"
+        );
+    }
+
+    #[test]
+    fn replaces_ligatures() {
+        assert_eq!(normalize_characters("ﬁnal ﬂow, oﬀer"), "final flow, offer");
+        assert_eq!(normalize_characters("eﬃcient baﬄe"), "efficient baffle");
+    }
+
+    #[test]
+    fn replaces_smart_quotes() {
+        assert_eq!(
+            normalize_characters("“quoted” text, it’s ‘single’"),
+            "\"quoted\" text, it's 'single'"
+        );
+    }
+
+    #[test]
+    fn replaces_special_spaces_and_removes_invisible_characters() {
+        assert_eq!(normalize_characters("a\u{00A0}b\u{200B}c"), "a bc");
+        assert_eq!(normalize_characters("trans\u{00AD}lation"), "translation");
+    }
+
+    #[test]
+    fn strips_list_and_quote_markers() {
+        assert_eq!(
+            strip_list_markers("• first\n- second\n* third\n+ fourth"),
+            "first\nsecond\nthird\nfourth"
+        );
+        assert_eq!(strip_list_markers("> > quoted\n>reply"), "quoted\nreply");
+    }
+
+    #[test]
+    fn keeps_markers_not_followed_by_whitespace() {
+        assert_eq!(strip_list_markers("-5 degrees"), "-5 degrees");
+        assert_eq!(strip_list_markers("*emphasis*"), "*emphasis*");
+        assert_eq!(strip_list_markers("+1 vote"), "+1 vote");
+    }
+
+    #[test]
+    fn keeps_indentation() {
+        let code = "fn main() {\n    println!();\n}";
+        assert_eq!(strip_list_markers(code), code);
+
+        let poem = "Roses are red,\n  violets are blue";
+        assert_eq!(strip_list_markers(poem), poem);
+
+        assert_eq!(strip_list_markers("  - nested item"), "  nested item");
+    }
+
+    #[test]
+    fn splits_camel_case_identifiers() {
+        assert_eq!(split_identifiers("getUserName"), "get user name");
+        assert_eq!(
+            split_identifiers("call UserService.getById()"),
+            "call user service.get by id()"
+        );
+    }
+
+    #[test]
+    fn splits_snake_case_identifiers() {
+        assert_eq!(split_identifiers("user_name"), "user name");
+        assert_eq!(split_identifiers("MAX_RETRY_COUNT"), "MAX RETRY COUNT");
+    }
+
+    #[test]
+    fn keeps_acronyms_in_identifiers() {
+        assert_eq!(split_identifiers("HTMLParser"), "HTML parser");
+        assert_eq!(split_identifiers("parseJSON"), "parse JSON");
+    }
+
+    #[test]
+    fn keeps_regular_words() {
+        assert_eq!(split_identifiers("Hello world, NASA"), "Hello world, NASA");
+        assert_eq!(split_identifiers("_private __init__"), "_private __init__");
+    }
+
+    #[test]
+    fn truncates_to_max_length() {
+        assert_eq!(truncate("hello world", 5), "hello");
+        // Trailing whitespace isn't left after cut
+        assert_eq!(truncate("hello world", 6), "hello");
+        // Length is counted in characters, not bytes
+        assert_eq!(truncate("привет мир", 6), "привет");
+    }
+
+    #[test]
+    fn truncates_trimmed_text() {
+        let settings = TextNormalizationSettings {
+            max_length: 5,
+            ..settings()
+        };
+        assert_eq!(normalize("  hello world", &settings), "hello");
+    }
+
+    #[test]
+    fn keeps_text_without_max_length() {
+        assert_eq!(truncate("hello world", 0), "hello world");
+        assert_eq!(truncate("hello", 10), "hello");
+    }
+
+    #[test]
+    fn applies_enabled_steps_only() {
+        let text = "- transla-\n  tion\n";
+        assert_eq!(normalize(text, &settings()), "- transla-\n  tion");
+
+        let settings = TextNormalizationSettings {
+            join_hyphenated_words: true,
+            join_wrapped_lines: true,
+            strip_list_markers: true,
+            ..settings()
+        };
+        assert_eq!(normalize(text, &settings), "translation");
+    }
+}
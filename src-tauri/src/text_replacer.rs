@@ -0,0 +1,117 @@
+use tauri::AppHandle;
+
+use crate::{notifications::show_notification, selection_translator};
+
+// Translates selected text without showing the window and pastes translation over the selection
+pub fn translate_and_replace(app: &AppHandle) {
+    // Translation is pasted with Windows input injection, so selection isn't even copied on other platforms
+    if !cfg!(windows) {
+        show_notification(
+            app,
+            "Unable to replace text",
+            Some("Replacing selected text is supported only on Windows."),
+        );
+        return;
+    }
+
+    selection_translator::translate_selection(
+        app,
+        "Error replacing text with translation",
+        replace_selection,
+    );
+}
+
+#[cfg(windows)]
+use windows_paste::replace_selection;
+
+#[cfg(not(windows))]
+async fn replace_selection(
+    _app: AppHandle,
+    _translation: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    Err("Replacing selected text is supported only on Windows.".into())
+}
+
+#[cfg(windows)]
+mod windows_paste {
+    use std::{
+        error::Error,
+        time::{Duration, Instant},
+    };
+
+    use log::{info, warn};
+    use tauri::{AppHandle, Manager};
+    use tauri_plugin_clipboard_manager::ClipboardExt;
+    use windows::Win32::System::DataExchange::{
+        GetClipboardSequenceNumber, GetOpenClipboardWindow,
+    };
+
+    use crate::{
+        clipboard_snapshot::ClipboardSnapshot, clipboard_watcher::ClipboardWatcher,
+        input_injection, settings::SettingsManager,
+    };
+
+    pub async fn replace_selection(
+        app: AppHandle,
+        translation: String,
+    ) -> Result<(), Box<dyn Error>> {
+        let settings = app.state::<SettingsManager>().read_settings().core;
+        let _own_write = app.state::<ClipboardWatcher>().begin_own_write();
+
+        // Translation is pasted through clipboard, so user's clipboard is restored afterwards
+        let clipboard_snapshot = if settings.preserve_clipboard {
+            ClipboardSnapshot::take()
+                .inspect_err(|err| warn!("Unable to take clipboard snapshot. {}", err))
+                .ok()
+        } else {
+            None
+        };
+
+        app.clipboard().write_text(translation)?;
+        let sequence_number = unsafe { GetClipboardSequenceNumber() };
+
+        if let Err(err) = input_injection::send_paste_command() {
+            restore_clipboard_snapshot(clipboard_snapshot);
+            return Err(err);
+        }
+
+        // Target app reads clipboard while processing the paste, so it can't be restored immediately
+        // Copy delay is used as a timeout, clipboard is restored as soon as the target app releases it
+        let timeout = Duration::from_millis(settings.copy_delay_milliseconds);
+        wait_for_clipboard_read(timeout).await;
+
+        // Sequence number is changed by any write, so newer clipboard content (e.g. copied by user) isn't overwritten
+        if unsafe { GetClipboardSequenceNumber() } == sequence_number {
+            restore_clipboard_snapshot(clipboard_snapshot);
+        } else {
+            info!("Clipboard has been changed after paste, so it isn't restored.");
+        }
+
+        Ok(())
+    }
+
+    // Clipboard is opened by the target app while translation is read, so the wait ends once it's closed again
+    async fn wait_for_clipboard_read(timeout: Duration) {
+        const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+        let start = Instant::now();
+        let mut was_opened = false;
+        while start.elapsed() < timeout {
+            let is_opened = unsafe { GetOpenClipboardWindow() }.0 != 0;
+            if was_opened && !is_opened {
+                return;
+            }
+
+            was_opened |= is_opened;
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    fn restore_clipboard_snapshot(clipboard_snapshot: Option<ClipboardSnapshot>) {
+        if let Some(clipboard_snapshot) = clipboard_snapshot {
+            clipboard_snapshot
+                .restore()
+                .unwrap_or_else(|err| warn!("Unable to restore clipboard. {}", err));
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tauri::{async_runtime::Receiver, AppHandle, Manager};
+use tokio::sync::mpsc::error::TrySendError;
+
+use crate::{
+    notifications::show_error_notification,
+    settings::{CoreSettings, SettingsManager},
+    translation_providers::{TranslateRequest, TranslateResult, TRANSLATE_RESULT_VERSION},
+};
+
+const TRANSLATION_CACHE_FILENAME: &str = "translation-cache.json";
+
+const SECONDS_IN_HOUR: u64 = 60 * 60;
+
+type CacheEntries = HashMap<String, CacheEntry>;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    result: TranslateResult,
+    created_at: u64,
+    last_accessed_at: u64,
+}
+
+pub struct TranslationCache {
+    app: AppHandle,
+    entries: Arc<Mutex<Option<CacheEntries>>>,
+    // Signals that entries have changed, entries themselves are taken by the flush task
+    cache_flush_sender: tauri::async_runtime::Sender<()>,
+}
+
+impl TranslationCache {
+    pub fn new(app: &AppHandle) -> Self {
+        // Single pending signal is enough, since every flush writes the whole cache
+        let (sender, receiver) = tauri::async_runtime::channel::<()>(1);
+        let entries = Arc::new(Mutex::new(None));
+        TranslationCache::spawn_cache_flush_receiver(app, entries.clone(), receiver);
+
+        Self {
+            app: app.clone(),
+            entries,
+            cache_flush_sender: sender,
+        }
+    }
+
+    pub fn get(&self, cache_scope: &str, request: &TranslateRequest) -> Option<TranslateResult> {
+        let settings = self.read_core_settings();
+        if settings.translation_cache_max_entries == 0 {
+            return None;
+        }
+
+        let key = Self::get_cache_key(cache_scope, request);
+        let now = Self::now();
+
+        let mut entries = self.entries.lock().unwrap();
+        let entries = entries.get_or_insert_with(|| self.read_entries());
+
+        let entry = entries.get_mut(&key)?;
+
+        // Results produced by an older version of the parser are treated as missing
+        if Self::is_expired(entry, &settings, now)
+            || entry.result.version != TRANSLATE_RESULT_VERSION
+        {
+            entries.remove(&key);
+            return None;
+        }
+
+        entry.last_accessed_at = now;
+        Some(entry.result.clone())
+    }
+
+    pub fn insert(&self, cache_scope: &str, request: &TranslateRequest, result: &TranslateResult) {
+        let settings = self.read_core_settings();
+        if settings.translation_cache_max_entries == 0 {
+            return;
+        }
+
+        let now = Self::now();
+
+        let mut entries = self.entries.lock().unwrap();
+        let entries = entries.get_or_insert_with(|| self.read_entries());
+
+        entries.insert(
+            Self::get_cache_key(cache_scope, request),
+            CacheEntry {
+                result: result.clone(),
+                created_at: now,
+                last_accessed_at: now,
+            },
+        );
+
+        Self::evict_entries(entries, &settings, now);
+
+        // Insert is called from async context, so blocking send can't be used here
+        // Full channel means that flush is already scheduled and it will pick up this entry as well
+        if let Err(TrySendError::Closed(_)) = self.cache_flush_sender.try_send(()) {
+            warn!("Unable to schedule translation cache flush, flush task has stopped.");
+        }
+    }
+
+    // Removes expired entries first, and then least recently used ones until cache fits the size limit
+    fn evict_entries(entries: &mut CacheEntries, settings: &CoreSettings, now: u64) {
+        entries.retain(|_, entry| !Self::is_expired(entry, settings, now));
+
+        let max_entries = settings.translation_cache_max_entries as usize;
+        if entries.len() <= max_entries {
+            return;
+        }
+
+        let mut keys_by_access_time: Vec<(String, u64)> = entries
+            .iter()
+            .map(|(key, entry)| (key.clone(), entry.last_accessed_at))
+            .collect();
+        keys_by_access_time.sort_by_key(|(_, last_accessed_at)| *last_accessed_at);
+
+        let entries_to_remove = entries.len() - max_entries;
+        for (key, _) in keys_by_access_time.into_iter().take(entries_to_remove) {
+            entries.remove(&key);
+        }
+    }
+
+    fn is_expired(entry: &CacheEntry, settings: &CoreSettings, now: u64) -> bool {
+        now.saturating_sub(entry.created_at)
+            > settings.translation_cache_ttl_hours as u64 * SECONDS_IN_HOUR
+    }
+
+    fn read_core_settings(&self) -> CoreSettings {
+        self.app.state::<SettingsManager>().read_settings().core
+    }
+
+    fn read_entries(&self) -> CacheEntries {
+        self.try_read_entries().unwrap_or_else(|e| {
+            show_error_notification(&self.app, e, "Error reading translation cache.");
+            CacheEntries::new()
+        })
+    }
+
+    fn try_read_entries(&self) -> Result<CacheEntries, Box<dyn Error>> {
+        let cache_path = TranslationCache::get_cache_path(&self.app);
+
+        if cache_path.exists() {
+            let cache_json = fs::read_to_string(cache_path)?;
+            Ok(serde_json::from_str(&cache_json)?)
+        } else {
+            Ok(CacheEntries::new())
+        }
+    }
+
+    fn spawn_cache_flush_receiver(
+        app: &AppHandle,
+        entries: Arc<Mutex<Option<CacheEntries>>>,
+        mut receiver: Receiver<()>,
+    ) {
+        let cache_path = TranslationCache::get_cache_path(app);
+
+        // Make sure cache dir exists
+        let cache_dir = cache_path.parent().unwrap();
+        fs::create_dir_all(cache_dir).unwrap();
+
+        tauri::async_runtime::spawn(async move {
+            while receiver.recv().await.is_some() {
+                TranslationCache::flush_entries(&entries, &cache_path)
+                    .unwrap_or_else(|err| warn!("Unable to write translation cache. {}", err));
+            }
+        });
+    }
+
+    fn flush_entries(
+        entries: &Mutex<Option<CacheEntries>>,
+        cache_path: &PathBuf,
+    ) -> Result<(), Box<dyn Error>> {
+        // Entries are serialized right under the lock, so the whole map isn't cloned for every flush
+        let cache_json = serde_json::to_string(&*entries.lock().unwrap())?;
+        fs::write(cache_path, cache_json)?;
+        Ok(())
+    }
+
+    fn get_cache_path(app: &AppHandle) -> PathBuf {
+        let cache_dir = app.path().app_data_dir().unwrap();
+        cache_dir.join(TRANSLATION_CACHE_FILENAME)
+    }
+
+    fn get_cache_key(cache_scope: &str, request: &TranslateRequest) -> String {
+        format!(
+            "{}|{}|{}|{}|{}",
+            cache_scope,
+            request.source_language,
+            request.target_language,
+            request.is_forced_translation,
+            request.sentence.trim()
+        )
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 100 * SECONDS_IN_HOUR;
+
+    fn request(sentence: &str, source_language: &str, target_language: &str) -> TranslateRequest {
+        TranslateRequest {
+            sentence: sentence.to_string(),
+            source_language: source_language.to_string(),
+            target_language: target_language.to_string(),
+            is_forced_translation: false,
+        }
+    }
+
+    fn settings(max_entries: u32, ttl_hours: u32) -> CoreSettings {
+        let mut settings = SettingsManager::read_default_settings().core;
+        settings.translation_cache_max_entries = max_entries;
+        settings.translation_cache_ttl_hours = ttl_hours;
+        settings
+    }
+
+    fn entry(created_at: u64, last_accessed_at: u64) -> CacheEntry {
+        CacheEntry {
+            result: TranslateResult::from_translation(
+                &request("hello", "en", "ru"),
+                "привет".to_string(),
+            ),
+            created_at,
+            last_accessed_at,
+        }
+    }
+
+    fn sorted_keys(entries: &CacheEntries) -> Vec<&str> {
+        let mut keys: Vec<&str> = entries.keys().map(String::as_str).collect();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn cache_key_includes_scope_languages_and_text() {
+        let key = TranslationCache::get_cache_key("google", &request("hello", "en", "ru"));
+
+        assert_ne!(
+            key,
+            TranslationCache::get_cache_key("deepl", &request("hello", "en", "ru"))
+        );
+        assert_ne!(
+            key,
+            TranslationCache::get_cache_key("google", &request("hello", "de", "ru"))
+        );
+        assert_ne!(
+            key,
+            TranslationCache::get_cache_key("google", &request("hello", "en", "de"))
+        );
+        assert_ne!(
+            key,
+            TranslationCache::get_cache_key("google", &request("world", "en", "ru"))
+        );
+    }
+
+    #[test]
+    fn cache_key_ignores_surrounding_whitespace() {
+        assert_eq!(
+            TranslationCache::get_cache_key("google", &request("hello", "en", "ru")),
+            TranslationCache::get_cache_key("google", &request(" hello\n", "en", "ru"))
+        );
+    }
+
+    #[test]
+    fn expires_entries_older_than_ttl() {
+        let settings = settings(10, 24);
+
+        assert!(!TranslationCache::is_expired(
+            &entry(NOW - 24 * SECONDS_IN_HOUR, NOW),
+            &settings,
+            NOW
+        ));
+        assert!(TranslationCache::is_expired(
+            &entry(NOW - 24 * SECONDS_IN_HOUR - 1, NOW),
+            &settings,
+            NOW
+        ));
+    }
+
+    #[test]
+    fn evicts_expired_entries() {
+        let mut entries = CacheEntries::from([
+            ("fresh".to_string(), entry(NOW - SECONDS_IN_HOUR, NOW)),
+            ("expired".to_string(), entry(NOW - 2 * SECONDS_IN_HOUR, NOW)),
+        ]);
+
+        TranslationCache::evict_entries(&mut entries, &settings(10, 1), NOW);
+
+        assert_eq!(sorted_keys(&entries), vec!["fresh"]);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_entries_over_size_limit() {
+        let mut entries = CacheEntries::from([
+            ("first".to_string(), entry(NOW, NOW - 3)),
+            ("second".to_string(), entry(NOW, NOW - 1)),
+            ("third".to_string(), entry(NOW, NOW - 2)),
+        ]);
+
+        TranslationCache::evict_entries(&mut entries, &settings(2, 24), NOW);
+
+        assert_eq!(sorted_keys(&entries), vec!["second", "third"]);
+    }
+}
@@ -0,0 +1,136 @@
+use std::error::Error;
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use serde::Deserialize;
+
+use crate::settings::DeepLProviderSettings;
+
+use super::{
+    get_url_host, ProviderRequest, TranslateRequest, TranslateResult, TranslationProvider,
+};
+
+const AUTO_DETECT_LANGUAGE: &str = "auto";
+
+// Regional variants are accepted by DeepL only for target language
+const REGIONAL_TARGET_LANGUAGES: [&str; 4] = ["EN-GB", "EN-US", "PT-BR", "PT-PT"];
+
+pub struct DeepLProvider {
+    settings: DeepLProviderSettings,
+}
+
+#[derive(Deserialize)]
+struct DeepLResponse {
+    translations: Vec<DeepLTranslation>,
+}
+
+#[derive(Deserialize)]
+struct DeepLTranslation {
+    text: String,
+}
+
+impl DeepLProvider {
+    pub fn new(settings: DeepLProviderSettings) -> Self {
+        Self { settings }
+    }
+
+    fn to_source_language(language: &str) -> String {
+        Self::to_base_language(language)
+    }
+
+    fn to_target_language(language: &str) -> String {
+        let language = language.to_uppercase();
+        if REGIONAL_TARGET_LANGUAGES.contains(&language.as_str()) {
+            language
+        } else {
+            Self::to_base_language(&language)
+        }
+    }
+
+    // Google-style codes like zh-CN are reduced to the language itself, e.g. ZH
+    fn to_base_language(language: &str) -> String {
+        language
+            .split(['-', '_'])
+            .next()
+            .unwrap_or(language)
+            .to_uppercase()
+    }
+}
+
+impl TranslationProvider for DeepLProvider {
+    fn cache_scope(&self) -> String {
+        format!("deepl|{}", self.settings.url)
+    }
+
+    fn allowed_hosts(&self) -> Vec<String> {
+        get_url_host(&self.settings.url)
+    }
+
+    fn build_request(&self, request: &TranslateRequest) -> Result<ProviderRequest, Box<dyn Error>> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("DeepL-Auth-Key {}", self.settings.api_key))?,
+        );
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+
+        // DeepL expects upper-case language codes and detects source language when it's omitted
+        let mut body = url::form_urlencoded::Serializer::new(String::new());
+        body.append_pair("text", request.sentence.trim())
+            .append_pair(
+                "target_lang",
+                &Self::to_target_language(&request.target_language),
+            );
+        if request.source_language != AUTO_DETECT_LANGUAGE {
+            body.append_pair(
+                "source_lang",
+                &Self::to_source_language(&request.source_language),
+            );
+        }
+
+        Ok(ProviderRequest {
+            url: self.settings.url.clone(),
+            headers,
+            body: body.finish(),
+        })
+    }
+
+    fn parse_response(
+        &self,
+        request: &TranslateRequest,
+        response: &str,
+    ) -> Result<TranslateResult, Box<dyn Error>> {
+        let response: DeepLResponse = serde_json::from_str(response)?;
+        let translation = response
+            .translations
+            .into_iter()
+            .next()
+            .ok_or("DeepL response doesn't contain translations")?;
+
+        Ok(TranslateResult::from_translation(request, translation.text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeepLProvider;
+
+    #[test]
+    fn source_language_is_reduced_to_base_language() {
+        assert_eq!(DeepLProvider::to_source_language("en"), "EN");
+        assert_eq!(DeepLProvider::to_source_language("zh-CN"), "ZH");
+        assert_eq!(DeepLProvider::to_source_language("pt-BR"), "PT");
+    }
+
+    #[test]
+    fn target_language_keeps_supported_regional_variants() {
+        assert_eq!(DeepLProvider::to_target_language("de"), "DE");
+        assert_eq!(DeepLProvider::to_target_language("pt-BR"), "PT-BR");
+        assert_eq!(DeepLProvider::to_target_language("pt-pt"), "PT-PT");
+        assert_eq!(DeepLProvider::to_target_language("en-GB"), "EN-GB");
+        assert_eq!(DeepLProvider::to_target_language("zh-CN"), "ZH");
+        assert_eq!(DeepLProvider::to_target_language("zh-TW"), "ZH");
+    }
+}
@@ -0,0 +1,104 @@
+use std::error::Error;
+
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, ORIGIN, USER_AGENT};
+use serde_json::{json, Value};
+
+use super::{
+    google_response_parser, ProviderRequest, TranslateRequest, TranslateResult, TranslationProvider,
+};
+
+const GOOGLE_TRANSLATE_ORIGIN: &str = "https://translate.google.com";
+const TRANSLATE_RPC_ID: &str = "MkEWBc";
+
+const ALLOWED_HOSTS: [&str; 1] = ["translate.google.com"];
+
+// Requests without a browser-like user agent are rejected by Google
+// Webview user agent is used when request comes from the frontend
+const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/130.0.0.0 Safari/537.36 Edg/130.0.0.0";
+
+pub struct GoogleTranslateProvider;
+
+impl GoogleTranslateProvider {
+    pub fn build_raw_request(url: String, body: String, user_agent: &str) -> ProviderRequest {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(user_agent)
+                .unwrap_or_else(|_| HeaderValue::from_static(DEFAULT_USER_AGENT)),
+        );
+        headers.insert(ORIGIN, HeaderValue::from_static(GOOGLE_TRANSLATE_ORIGIN));
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded;charset=UTF-8"),
+        );
+
+        ProviderRequest { url, headers, body }
+    }
+
+    fn get_rpc_url(rpc_id: &str) -> String {
+        format!("{GOOGLE_TRANSLATE_ORIGIN}/_/TranslateWebserverUi/data/batchexecute?rpcids={rpc_id}&hl=ru&soc-app=1&soc-platform=1&soc-device=1&rt=c")
+    }
+
+    // Response is a sequence of lines, one of which contains a JSON array with rpc response
+    // The actual rpc payload is a JSON-encoded string inside this array
+    fn parse_rpc_response(response: &str, rpc_id: &str) -> Result<Value, Box<dyn Error>> {
+        let response_line = response
+            .lines()
+            .find(|line| line.contains(rpc_id))
+            .ok_or("Unable to find google response")?;
+
+        let response_line_json: Value = serde_json::from_str(response_line)?;
+        let response_content = response_line_json[0][2]
+            .as_str()
+            .ok_or("Google response doesn't contain rpc payload")?;
+
+        Ok(serde_json::from_str(response_content)?)
+    }
+}
+
+impl TranslationProvider for GoogleTranslateProvider {
+    fn cache_scope(&self) -> String {
+        "google".to_string()
+    }
+
+    fn allowed_hosts(&self) -> Vec<String> {
+        ALLOWED_HOSTS.iter().map(|host| host.to_string()).collect()
+    }
+
+    fn build_request(&self, request: &TranslateRequest) -> Result<ProviderRequest, Box<dyn Error>> {
+        let forced_translation_flag = if request.is_forced_translation {
+            Value::Null
+        } else {
+            json!(1)
+        };
+        let rpc_data = json!([
+            [
+                request.sentence.trim(),
+                request.source_language,
+                request.target_language,
+                forced_translation_flag
+            ],
+            []
+        ]);
+        let rpc_request = json!([[[TRANSLATE_RPC_ID, rpc_data.to_string(), null, "generic"]]]);
+
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("f.req", &rpc_request.to_string())
+            .finish();
+
+        Ok(Self::build_raw_request(
+            Self::get_rpc_url(TRANSLATE_RPC_ID),
+            body,
+            DEFAULT_USER_AGENT,
+        ))
+    }
+
+    fn parse_response(
+        &self,
+        request: &TranslateRequest,
+        response: &str,
+    ) -> Result<TranslateResult, Box<dyn Error>> {
+        let root = Self::parse_rpc_response(response, TRANSLATE_RPC_ID)?;
+        google_response_parser::parse(&root, &request.sentence)
+    }
+}
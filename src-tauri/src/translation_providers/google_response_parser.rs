@@ -0,0 +1,357 @@
+use std::error::Error;
+
+use serde_json::Value;
+
+use super::translate_result::{
+    TranslateResult, TranslateResultCategory, TranslateResultCategoryEntry,
+    TranslateResultDefinitionCategory, TranslateResultDefinitionCategoryEntry,
+    TranslateResultSentence, TRANSLATE_RESULT_VERSION,
+};
+
+const MAX_SIMILAR_WORDS_IN_CATEGORY: usize = 5;
+
+// Response structure:
+// [
+// 0: [
+//      0: "<transcription>",
+//      1: [
+//           0: [
+//                0: [<..>, <suggested_input_as_html>, ..],
+//                1: "<corrected_input>",
+//                2: <..>,
+//                3: <..>,
+//                4: "<suggested_input>"
+//              ],
+//           1: ["<suggested_language>"]
+//         ],
+//         ....
+//    ],
+// 1: [
+//   0: [
+//      0: [
+//           0: <..>,
+//           1: "<transliteration>",
+//           2: <..>,
+//           3: <..>,
+//           4: <..>,
+//           5: [
+//               0: [
+//                    0: "<translation">,
+//                       ...
+//                  ]
+//              ]
+//         ]
+//      ]
+//    ],
+// 2: "<detected_language>",
+// 3: [
+//      0: "<base_form>",
+//      1: [
+//         0: [
+//             0: [
+//                  0: <..>,
+//                  1: [
+//                       0: [
+//                            0: "<definition>",
+//                            1: "<sample>",
+//                            2: <..>,
+//                            3: <..>,
+//                            4: <..>,
+//                            5: [
+//                              0: [[["<synonym_1>"], ["<synonym_2>"], ...]]]
+//                            ]
+//                          ]
+//                     ],
+//                  2: <..>,
+//                  3: <part_of_speech>
+//                ]
+//            ],
+//            ....
+//         ],
+//      2: <..>,
+//      3: <..>,
+//      4: [
+//           0: [
+//                0: [
+//                      0: "<part_of_speech>"
+//                      1: [
+//                            0: [
+//                                  0: ["<similar_word>", ...]
+//                               ]
+//                          ]
+//                   ],
+//                   ....
+//              ]
+//         ],
+//      5: [
+//           0: [
+//                 0: [
+//                      0: null,
+//                      1: [
+//                           0: ["<variant_1>", <...>, ["<reverse_translation_1>", "<reverse_translation_2>", ..], <score>, null]
+//                              .....
+//                         ],
+//                      2: ...,
+//                      3: ...,
+//                      4: <part_of_speech>,
+//                    ],
+//                    ...
+//               ]
+//         ]
+//   ]
+pub fn parse(root: &Value, input: &str) -> Result<TranslateResult, Box<dyn Error>> {
+    if !root.is_array() {
+        return Err("Google response root is not an array".into());
+    }
+
+    let sentence = parse_sentence(root, input);
+    let categories = parse_translate_categories(root);
+    let definitions = parse_definitions(root);
+
+    // Since response isn't always accurate, use top category result if available
+    let top_category_translation = categories
+        .first()
+        .and_then(|category| category.entries.first())
+        .map(|entry| entry.word.clone());
+
+    Ok(TranslateResult {
+        sentence: TranslateResultSentence {
+            translation: top_category_translation.or_else(|| sentence.translation.clone()),
+            ..sentence
+        },
+        categories,
+        definitions,
+        version: TRANSLATE_RESULT_VERSION.to_string(),
+    })
+}
+
+fn parse_sentence(root: &Value, input: &str) -> TranslateResultSentence {
+    let input_section = &root[0];
+
+    let mut suggestion = None;
+    let mut origin = None;
+    let mut language_suggestion = None;
+    let mut transcription = None;
+    if is_non_empty_array(input_section) {
+        suggestion = as_string(&input_section[1][0][4]);
+
+        let corrected_input = as_string(&input_section[1][0][1]);
+        origin = match (&corrected_input, &suggestion) {
+            (Some(_), Some(suggestion)) => Some(suggestion.clone()),
+            _ => Some(input.to_string()),
+        };
+
+        language_suggestion = as_string(&input_section[1][1][0]);
+        transcription = as_string(&input_section[0]);
+    }
+
+    let translation_section = &root[1];
+    let translation = if is_non_empty_array(translation_section) {
+        as_string(&translation_section[0][0][5][0][0])
+    } else {
+        None
+    };
+
+    let mut similar_words: Vec<String> = Vec::new();
+    for similar_words_category in as_array(&root[3][4][0]) {
+        for similar_words_sub_category in as_array(&similar_words_category[1]) {
+            let category_words = as_array(&similar_words_sub_category[0])
+                .iter()
+                .filter_map(as_string)
+                .take(MAX_SIMILAR_WORDS_IN_CATEGORY);
+            similar_words.extend(category_words);
+        }
+    }
+
+    TranslateResultSentence {
+        input: input.to_string(),
+        translation,
+        transcription,
+        origin,
+        suggestion,
+        language_suggestion,
+        detected_language: as_string(&root[2]),
+        similar_words,
+    }
+}
+
+fn parse_translate_categories(root: &Value) -> Vec<TranslateResultCategory> {
+    let base_form = get_base_form(root);
+
+    as_array(&root[3][5][0])
+        .iter()
+        .map(|category| {
+            let entries = as_array(&category[1])
+                .iter()
+                .map(|entry| TranslateResultCategoryEntry {
+                    word: as_string(&entry[0]).unwrap_or_default(),
+                    reverse_translations: as_array(&entry[2])
+                        .iter()
+                        .filter_map(as_string)
+                        .collect(),
+                    score: entry[3].as_f64().unwrap_or(0.0),
+                })
+                .collect();
+
+            TranslateResultCategory {
+                part_of_speech: map_part_of_speech(&category[4]),
+                base_form: base_form.clone(),
+                entries,
+            }
+        })
+        .collect()
+}
+
+fn parse_definitions(root: &Value) -> Vec<TranslateResultDefinitionCategory> {
+    let base_form = get_base_form(root);
+
+    as_array(&root[3][1][0])
+        .iter()
+        .map(|definition_category| {
+            let entries = as_array(&definition_category[1])
+                .iter()
+                .map(|entry| TranslateResultDefinitionCategoryEntry {
+                    definition: as_string(&entry[0]).unwrap_or_default(),
+                    sample: as_string(&entry[1]),
+                    synonyms: as_array(&entry[5][0][0])
+                        .iter()
+                        .filter_map(|synonym| as_string(&synonym[0]))
+                        .collect(),
+                })
+                .collect();
+
+            TranslateResultDefinitionCategory {
+                part_of_speech: map_part_of_speech(&definition_category[3]),
+                base_form: base_form.clone(),
+                entries,
+            }
+        })
+        .collect()
+}
+
+fn get_base_form(root: &Value) -> String {
+    as_string(&root[3][0]).unwrap_or_else(|| "N/A".to_string())
+}
+
+fn map_part_of_speech(value: &Value) -> Option<String> {
+    let index = value.as_u64()?;
+    let part_of_speech = match index {
+        1 => "Noun",
+        2 => "Verb",
+        3 => "Adjective",
+        4 => "Adverb",
+        5 => "Preposition",
+        6 => "Abbreviation",
+        7 => "Conjunction",
+        8 => "Pronoun",
+        9 => "Interjection",
+        10 => "Phrase",
+        11 => "Prefix",
+        12 => "Suffix",
+        13 => "Article",
+        14 => "Combining form",
+        15 => "Numeral",
+        16 => "Auxiliary verb",
+        17 => "Exclamation",
+        18 => "Plural",
+        19 => "Particle",
+        index => return Some(index.to_string()),
+    };
+
+    Some(part_of_speech.to_string())
+}
+
+fn as_string(value: &Value) -> Option<String> {
+    value.as_str().map(|value| value.to_string())
+}
+
+fn as_array(value: &Value) -> &[Value] {
+    value.as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+fn is_non_empty_array(value: &Value) -> bool {
+    !as_array(value).is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::parse;
+
+    const HELLO_RESPONSE_JSON: &str = include_str!("./fixtures/google-hello-response.json");
+    const GOOD_LUCK_RESPONSE_JSON: &str = include_str!("./fixtures/google-good-luck-response.json");
+
+    #[test]
+    fn parses_recorded_response() {
+        let root: Value = serde_json::from_str(HELLO_RESPONSE_JSON).unwrap();
+
+        let result = parse(&root, "hello").unwrap();
+
+        assert_eq!(result.version, "v4.0");
+
+        let sentence = &result.sentence;
+        assert_eq!(sentence.input, "hello");
+        // Top category entry takes precedence over sentence translation
+        assert_eq!(sentence.translation.as_deref(), Some("привет"));
+        assert_eq!(sentence.transcription.as_deref(), Some("həˈlō"));
+        assert_eq!(sentence.origin.as_deref(), Some("hello"));
+        assert_eq!(sentence.suggestion, None);
+        assert_eq!(sentence.language_suggestion, None);
+        assert_eq!(sentence.detected_language.as_deref(), Some("en"));
+        assert_eq!(
+            sentence.similar_words,
+            vec!["hi", "hey", "howdy", "hiya", "greetings"]
+        );
+
+        assert_eq!(result.categories.len(), 1);
+        let category = &result.categories[0];
+        assert_eq!(category.part_of_speech.as_deref(), Some("Exclamation"));
+        assert_eq!(category.base_form, "hello");
+        assert_eq!(category.entries.len(), 2);
+        assert_eq!(category.entries[0].word, "привет");
+        assert_eq!(
+            category.entries[0].reverse_translations,
+            vec!["hello", "hi", "hallo"]
+        );
+        assert_eq!(category.entries[0].score, 0.5);
+        assert_eq!(category.entries[1].word, "здравствуйте");
+
+        assert_eq!(result.definitions.len(), 1);
+        let definition_category = &result.definitions[0];
+        assert_eq!(
+            definition_category.part_of_speech.as_deref(),
+            Some("Exclamation")
+        );
+        assert_eq!(definition_category.base_form, "hello");
+        let definition = &definition_category.entries[0];
+        assert_eq!(
+            definition.definition,
+            "used as a greeting or to begin a phone conversation."
+        );
+        assert_eq!(definition.sample.as_deref(), Some("hello there, Katie!"));
+        assert_eq!(definition.synonyms, vec!["hi", "greetings", "howdy"]);
+    }
+
+    #[test]
+    fn skips_missing_part_of_speech() {
+        let root: Value = serde_json::from_str(GOOD_LUCK_RESPONSE_JSON).unwrap();
+
+        let result = parse(&root, "good luck").unwrap();
+
+        assert_eq!(result.categories.len(), 1);
+        assert_eq!(result.categories[0].part_of_speech, None);
+        assert_eq!(result.definitions.len(), 1);
+        assert_eq!(result.definitions[0].part_of_speech, None);
+
+        // Field is left out, same as for results stored before parsing was moved to backend
+        let serialized = serde_json::to_value(&result).unwrap();
+        assert!(serialized["categories"][0].get("partOfSpeech").is_none());
+        assert!(serialized["definitions"][0].get("partOfSpeech").is_none());
+    }
+
+    #[test]
+    fn rejects_non_array_response() {
+        assert!(parse(&Value::Null, "hello").is_err());
+    }
+}
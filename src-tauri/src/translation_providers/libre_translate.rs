@@ -0,0 +1,68 @@
+use std::error::Error;
+
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::settings::LibreTranslateProviderSettings;
+
+use super::{
+    get_url_host, ProviderRequest, TranslateRequest, TranslateResult, TranslationProvider,
+};
+
+pub struct LibreTranslateProvider {
+    settings: LibreTranslateProviderSettings,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LibreTranslateResponse {
+    translated_text: String,
+}
+
+impl LibreTranslateProvider {
+    pub fn new(settings: LibreTranslateProviderSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl TranslationProvider for LibreTranslateProvider {
+    fn cache_scope(&self) -> String {
+        format!("libreTranslate|{}", self.settings.url)
+    }
+
+    fn allowed_hosts(&self) -> Vec<String> {
+        get_url_host(&self.settings.url)
+    }
+
+    fn build_request(&self, request: &TranslateRequest) -> Result<ProviderRequest, Box<dyn Error>> {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        let body = json!({
+            "q": request.sentence.trim(),
+            "source": request.source_language,
+            "target": request.target_language,
+            "format": "text",
+            "api_key": self.settings.api_key,
+        });
+
+        Ok(ProviderRequest {
+            url: self.settings.url.clone(),
+            headers,
+            body: body.to_string(),
+        })
+    }
+
+    fn parse_response(
+        &self,
+        request: &TranslateRequest,
+        response: &str,
+    ) -> Result<TranslateResult, Box<dyn Error>> {
+        let response: LibreTranslateResponse = serde_json::from_str(response)?;
+        Ok(TranslateResult::from_translation(
+            request,
+            response.translated_text,
+        ))
+    }
+}
@@ -0,0 +1,153 @@
+use std::error::Error;
+
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+
+use crate::settings::{TranslationProviderKind, TranslationProviderSettings, TranslationSettings};
+
+mod deepl;
+mod google;
+mod google_response_parser;
+mod libre_translate;
+mod open_ai;
+mod translate_result;
+
+pub use deepl::DeepLProvider;
+pub use google::GoogleTranslateProvider;
+pub use libre_translate::LibreTranslateProvider;
+pub use open_ai::OpenAiProvider;
+pub use translate_result::{TranslateResult, TRANSLATE_RESULT_VERSION};
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslateRequest {
+    pub sentence: String,
+    pub source_language: String,
+    pub target_language: String,
+    pub is_forced_translation: bool,
+}
+
+impl TranslateRequest {
+    pub fn from_settings(settings: &TranslationSettings, sentence: String) -> Self {
+        Self {
+            sentence,
+            source_language: settings.source_language.clone(),
+            target_language: settings.target_language.clone(),
+            is_forced_translation: false,
+        }
+    }
+}
+
+// Provider-agnostic description of an outbound POST request
+// Actual execution (proxy, timeouts, etc.) is handled by the requests executor
+pub struct ProviderRequest {
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+pub trait TranslationProvider: Send + Sync {
+    // Identifies the backend producing results, so cached results aren't shared between different endpoints or models
+    fn cache_scope(&self) -> String;
+
+    // Hosts the provider talks to, raw requests from the webview are restricted to them
+    fn allowed_hosts(&self) -> Vec<String>;
+
+    fn build_request(&self, request: &TranslateRequest) -> Result<ProviderRequest, Box<dyn Error>>;
+
+    fn parse_response(
+        &self,
+        request: &TranslateRequest,
+        response: &str,
+    ) -> Result<TranslateResult, Box<dyn Error>>;
+}
+
+pub fn is_url_allowed(provider: &dyn TranslationProvider, url: &reqwest::Url) -> bool {
+    url.scheme() == "https"
+        && url.host_str().is_some_and(|host| {
+            provider
+                .allowed_hosts()
+                .iter()
+                .any(|allowed_host| allowed_host == host)
+        })
+}
+
+// Raw requests come from the webview, text to speech is always served by Google whichever provider translates
+pub fn is_raw_request_allowed(settings: &TranslationProviderSettings, url: &reqwest::Url) -> bool {
+    is_url_allowed(&GoogleTranslateProvider, url)
+        || is_url_allowed(create_provider(settings).as_ref(), url)
+}
+
+// Self-hosted providers are allowed to talk only to the configured endpoint
+fn get_url_host(url: &str) -> Vec<String> {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(|host| host.to_string()))
+        .into_iter()
+        .collect()
+}
+
+pub fn create_provider(settings: &TranslationProviderSettings) -> Box<dyn TranslationProvider> {
+    match settings.kind {
+        TranslationProviderKind::Google => Box::new(GoogleTranslateProvider),
+        TranslationProviderKind::DeepL => Box::new(DeepLProvider::new(settings.deepl.clone())),
+        TranslationProviderKind::LibreTranslate => Box::new(LibreTranslateProvider::new(
+            settings.libre_translate.clone(),
+        )),
+        TranslationProviderKind::OpenAi => Box::new(OpenAiProvider::new(settings.open_ai.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::settings::SettingsManager;
+
+    use super::*;
+
+    fn provider_settings(kind: TranslationProviderKind) -> TranslationProviderSettings {
+        let mut settings = SettingsManager::read_default_settings()
+            .translation
+            .provider;
+        settings.kind = kind;
+        settings
+    }
+
+    fn is_allowed(kind: TranslationProviderKind, url: &str) -> bool {
+        is_raw_request_allowed(&provider_settings(kind), &reqwest::Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn allows_google_text_to_speech_for_any_provider() {
+        let url =
+            "https://translate.google.com/_/TranslateWebserverUi/data/batchexecute?rpcids=jQ1olc";
+
+        assert!(is_allowed(TranslationProviderKind::Google, url));
+        assert!(is_allowed(TranslationProviderKind::DeepL, url));
+        assert!(is_allowed(TranslationProviderKind::LibreTranslate, url));
+        assert!(is_allowed(TranslationProviderKind::OpenAi, url));
+    }
+
+    #[test]
+    fn allows_configured_provider_host() {
+        assert!(is_allowed(
+            TranslationProviderKind::DeepL,
+            "https://api-free.deepl.com/v2/translate"
+        ));
+        assert!(!is_allowed(
+            TranslationProviderKind::Google,
+            "https://api-free.deepl.com/v2/translate"
+        ));
+    }
+
+    #[test]
+    fn rejects_other_hosts_and_plain_http() {
+        assert!(!is_allowed(
+            TranslationProviderKind::DeepL,
+            "https://example.com/"
+        ));
+        assert!(!is_allowed(
+            TranslationProviderKind::DeepL,
+            "http://translate.google.com/"
+        ));
+    }
+}
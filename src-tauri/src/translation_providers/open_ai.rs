@@ -0,0 +1,99 @@
+use std::error::Error;
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::settings::OpenAiProviderSettings;
+
+use super::{
+    get_url_host, ProviderRequest, TranslateRequest, TranslateResult, TranslationProvider,
+};
+
+pub struct OpenAiProvider {
+    settings: OpenAiProviderSettings,
+}
+
+#[derive(Deserialize)]
+struct ChatCompletionResponse {
+    choices: Vec<ChatCompletionChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChatCompletionChoice {
+    message: ChatCompletionMessage,
+}
+
+#[derive(Deserialize)]
+struct ChatCompletionMessage {
+    content: String,
+}
+
+impl OpenAiProvider {
+    pub fn new(settings: OpenAiProviderSettings) -> Self {
+        Self { settings }
+    }
+
+    fn get_system_prompt(request: &TranslateRequest) -> String {
+        format!(
+            "Translate the text provided by the user from the language with code \"{}\" to the language with code \"{}\". Reply with the translation only.",
+            request.source_language, request.target_language
+        )
+    }
+}
+
+impl TranslationProvider for OpenAiProvider {
+    fn cache_scope(&self) -> String {
+        format!("openAi|{}|{}", self.settings.url, self.settings.model)
+    }
+
+    fn allowed_hosts(&self) -> Vec<String> {
+        get_url_host(&self.settings.url)
+    }
+
+    fn build_request(&self, request: &TranslateRequest) -> Result<ProviderRequest, Box<dyn Error>> {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        // Local OpenAI-compatible servers usually don't require a key
+        if let Some(api_key) = &self.settings.api_key {
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {api_key}"))?,
+            );
+        }
+
+        let body = json!({
+            "model": self.settings.model,
+            "temperature": 0,
+            "messages": [
+                { "role": "system", "content": Self::get_system_prompt(request) },
+                { "role": "user", "content": request.sentence.trim() }
+            ]
+        });
+
+        Ok(ProviderRequest {
+            url: self.settings.url.clone(),
+            headers,
+            body: body.to_string(),
+        })
+    }
+
+    fn parse_response(
+        &self,
+        request: &TranslateRequest,
+        response: &str,
+    ) -> Result<TranslateResult, Box<dyn Error>> {
+        let response: ChatCompletionResponse = serde_json::from_str(response)?;
+        let choice = response
+            .choices
+            .into_iter()
+            .next()
+            .ok_or("OpenAI response doesn't contain choices")?;
+
+        Ok(TranslateResult::from_translation(
+            request,
+            choice.message.content.trim().to_string(),
+        ))
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use super::TranslateRequest;
+
+// Stored as-is in the history, so any breaking change to the structure must bump the version
+pub const TRANSLATE_RESULT_VERSION: &str = "v4.0";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslateResult {
+    pub sentence: TranslateResultSentence,
+    pub categories: Vec<TranslateResultCategory>,
+    pub definitions: Vec<TranslateResultDefinitionCategory>,
+    pub version: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslateResultSentence {
+    pub input: String,
+    pub translation: Option<String>,
+    pub transcription: Option<String>,
+    pub origin: Option<String>,
+    pub suggestion: Option<String>,
+    pub language_suggestion: Option<String>,
+    pub detected_language: Option<String>,
+    pub similar_words: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslateResultCategory {
+    // Missing in some responses, it's left out instead of being stored as a placeholder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_of_speech: Option<String>,
+    pub base_form: String,
+    pub entries: Vec<TranslateResultCategoryEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslateResultCategoryEntry {
+    pub word: String,
+    pub reverse_translations: Vec<String>,
+    pub score: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslateResultDefinitionCategory {
+    // Missing in some responses, it's left out instead of being stored as a placeholder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_of_speech: Option<String>,
+    pub base_form: String,
+    pub entries: Vec<TranslateResultDefinitionCategoryEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslateResultDefinitionCategoryEntry {
+    pub definition: String,
+    pub sample: Option<String>,
+    pub synonyms: Vec<String>,
+}
+
+impl TranslateResult {
+    // Used by providers which return only the translated sentence, without dictionary data
+    pub fn from_translation(request: &TranslateRequest, translation: String) -> Self {
+        Self {
+            sentence: TranslateResultSentence {
+                input: request.sentence.clone(),
+                translation: Some(translation),
+                transcription: None,
+                origin: Some(request.sentence.clone()),
+                suggestion: None,
+                language_suggestion: None,
+                detected_language: None,
+                similar_words: Vec::new(),
+            },
+            categories: Vec::new(),
+            definitions: Vec::new(),
+            version: TRANSLATE_RESULT_VERSION.to_string(),
+        }
+    }
+}
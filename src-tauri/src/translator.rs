@@ -0,0 +1,70 @@
+use std::error::Error;
+
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    requests_executor::RequestsExecutor,
+    settings::{ProxySettings, SettingsManager},
+    translation_cache::TranslationCache,
+    translation_providers::{self, TranslateRequest, TranslateResult},
+};
+
+const PROXY_TEST_SENTENCE: &str = "Hello";
+
+pub async fn translate(
+    app: &AppHandle,
+    request: &TranslateRequest,
+) -> Result<TranslateResult, Box<dyn Error>> {
+    let settings = app.state::<SettingsManager>().read_settings();
+    let provider = translation_providers::create_provider(&settings.translation.provider);
+    let cache_scope = provider.cache_scope();
+
+    let translation_cache = app.state::<TranslationCache>();
+    if let Some(cached_result) = translation_cache.get(&cache_scope, request) {
+        return Ok(cached_result);
+    }
+
+    let provider_request = provider.build_request(request)?;
+    let response = app
+        .state::<RequestsExecutor>()
+        .execute_request(provider_request)
+        .await?;
+
+    let result = provider.parse_response(request, &response)?;
+    translation_cache.insert(&cache_scope, request, &result);
+
+    Ok(result)
+}
+
+// Translates text in the background (without the window), only the main translation is needed in this case
+pub async fn translate_sentence(
+    app: &AppHandle,
+    request: &TranslateRequest,
+) -> Result<String, Box<dyn Error>> {
+    translate(app, request)
+        .await?
+        .sentence
+        .translation
+        .filter(|translation| !translation.is_empty())
+        .ok_or_else(|| "Translation is empty.".into())
+}
+
+// Translates a sample sentence through the provided proxy, to check that proxy settings actually work
+pub async fn test_proxy(
+    app: &AppHandle,
+    proxy_settings: &ProxySettings,
+) -> Result<TranslateResult, Box<dyn Error>> {
+    let settings = app.state::<SettingsManager>().read_settings();
+    let provider = translation_providers::create_provider(&settings.translation.provider);
+
+    let request =
+        TranslateRequest::from_settings(&settings.translation, PROXY_TEST_SENTENCE.to_string());
+
+    let provider_request = provider.build_request(&request)?;
+    let response = app
+        .state::<RequestsExecutor>()
+        .execute_request_through_proxy(provider_request, proxy_settings)
+        .await?;
+
+    provider.parse_response(&request, &response)
+}
@@ -10,6 +10,7 @@ use tauri::{
 use tauri_plugin_shell::ShellExt;
 
 use crate::{
+    clipboard_watcher::ClipboardWatcher,
     events_manager::{EventsManager, PLAY_START_EVENT, PLAY_STOP_EVENT},
     notifications::show_error_notification,
     settings::{PartialSettings, PartialTranslationSettings, SettingsManager, Tag},
@@ -19,6 +20,7 @@ use crate::{
 };
 
 const TRANSLATE_MENU_ITEM_ID: &str = "translate";
+const CLIPBOARD_WATCH_MENU_ITEM_ID: &str = "clipboard_watch";
 const HISTORY_MENU_ITEM_ID: &str = "history";
 const SETTINGS_MENU_ITEM_ID: &str = "settings";
 const LOGS_MENU_ITEM_ID: &str = "logs";
@@ -40,7 +42,12 @@ impl AppTrayIcon {
     pub fn new(app: &AppHandle) -> Self {
         let shortcuts_manager = app.state::<ShortcutsManager>();
         let settings_manager = app.state::<SettingsManager>();
-        let menu = Self::build_menu(app, shortcuts_manager.is_suspended());
+        let clipboard_watcher = app.state::<ClipboardWatcher>();
+        let menu = Self::build_menu(
+            app,
+            shortcuts_manager.is_suspended(),
+            clipboard_watcher.is_watching(),
+        );
 
         let tray_icon = TrayIconBuilder::new()
             .menu(&menu)
@@ -58,6 +65,11 @@ impl AppTrayIcon {
             self_clone.handle_suspended_state_change(suspended);
         });
 
+        let self_clone = app_tray_icon.clone();
+        clipboard_watcher.add_watching_change_handler(move |_| {
+            self_clone.update_menu();
+        });
+
         let self_clone = app_tray_icon.clone();
         settings_manager.add_change_handler(move |old_settings, new_settings| {
             if old_settings.translation.tags != new_settings.translation.tags {
@@ -75,6 +87,9 @@ impl AppTrayIcon {
             TRANSLATE_MENU_ITEM_ID => {
                 Self::translate_from_clipboard(app);
             }
+            CLIPBOARD_WATCH_MENU_ITEM_ID => {
+                app.state::<ClipboardWatcher>().toggle();
+            }
             HISTORY_MENU_ITEM_ID => {
                 window_manager::show_history_window(app);
             }
@@ -108,14 +123,28 @@ impl AppTrayIcon {
         }
     }
 
-    fn translate_from_clipboard(app: &AppHandle) {
-        app.state::<TextExtractor>().extract_text_from_clipboard();
-        let window = window_manager::show_translation_window(app);
-        app.state::<EventsManager>()
-            .emit_translate_text_command(&window, false);
+    pub fn translate_from_clipboard(app: &AppHandle) {
+        // Text might be recognized from clipboard image, which takes a while, so caller isn't blocked
+        let app = app.clone();
+        tauri::async_runtime::spawn_blocking(move || {
+            let settings = app.state::<SettingsManager>().read_settings();
+            // Window is shown anyway, so it tells that there is no text to translate
+            if let Err(err) = app
+                .state::<TextExtractor>()
+                .extract_text_from_clipboard(&settings)
+            {
+                show_error_notification(&app, err, "Error reading text from clipboard");
+            }
+
+            let window = window_manager::show_translation_window(&app);
+            app.state::<EventsManager>()
+                .emit_translate_text_command(&window, false);
+        });
     }
 
     fn handle_suspended_state_change(&self, suspended: bool) {
+        let clipboard_watcher = self.tray_icon.app_handle().state::<ClipboardWatcher>();
+
         self.tray_icon
             .set_icon(Some(Self::get_tray_icon_image(suspended)))
             .unwrap();
@@ -123,17 +152,20 @@ impl AppTrayIcon {
             .set_menu(Some(Self::build_menu(
                 self.tray_icon.app_handle(),
                 suspended,
+                clipboard_watcher.is_watching(),
             )))
             .unwrap();
     }
 
     fn update_menu(&self) {
         let shortcuts_manager = self.tray_icon.app_handle().state::<ShortcutsManager>();
+        let clipboard_watcher = self.tray_icon.app_handle().state::<ClipboardWatcher>();
 
         self.tray_icon
             .set_menu(Some(Self::build_menu(
                 self.tray_icon.app_handle(),
                 shortcuts_manager.is_suspended(),
+                clipboard_watcher.is_watching(),
             )))
             .unwrap();
     }
@@ -176,11 +208,20 @@ impl AppTrayIcon {
         });
     }
 
-    fn build_menu(app: &AppHandle, suspended: bool) -> Menu<Wry> {
+    fn build_menu(app: &AppHandle, suspended: bool, clipboard_watching: bool) -> Menu<Wry> {
         let translate_item =
             MenuItemBuilder::with_id(TRANSLATE_MENU_ITEM_ID, "Translate from clipboard")
                 .build(app)
                 .unwrap();
+        let clipboard_watch_item = CheckMenuItem::with_id(
+            app,
+            CLIPBOARD_WATCH_MENU_ITEM_ID,
+            "Watch clipboard",
+            true,
+            clipboard_watching,
+            None::<&str>,
+        )
+        .unwrap();
         let history_item = MenuItemBuilder::with_id(HISTORY_MENU_ITEM_ID, "History")
             .build(app)
             .unwrap();
@@ -207,6 +248,7 @@ impl AppTrayIcon {
 
         let menu = MenuBuilder::new(app)
             .item(&translate_item)
+            .item(&clipboard_watch_item)
             .item(&history_item)
             .item(&settings_item)
             .separator()
@@ -1,6 +1,5 @@
-use std::ffi::c_void;
-
 use tauri::{AppHandle, Manager, WebviewWindow};
+#[cfg(windows)]
 use windows::Win32::{
     Foundation::RECT,
     UI::WindowsAndMessaging::{SPI_GETWORKAREA, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS},
@@ -21,6 +20,17 @@ pub const ABOUT_WINDOW_LABEL: &str = "about";
 
 pub const WEB_VIEW_PATH: &str = "index.html";
 
+#[cfg(not(windows))]
+const DEFAULT_WORK_AREA_WIDTH: i32 = 1920;
+#[cfg(not(windows))]
+const DEFAULT_WORK_AREA_HEIGHT: i32 = 1080;
+
+// Only right and bottom edges are needed to position translation window
+struct WorkArea {
+    right: i32,
+    bottom: i32,
+}
+
 struct SizeAndPosition {
     width: f64,
     height: f64,
@@ -277,7 +287,7 @@ fn get_translation_window_position(
     app: &AppHandle,
     settings: &TranslationWindowSettings,
 ) -> (f64, f64) {
-    let work_area = get_primary_monitor_work_area();
+    let work_area = get_primary_monitor_work_area(app);
 
     if is_saved_window_position_valid(app, settings) {
         return (settings.x.unwrap() as f64, settings.y.unwrap() as f64);
@@ -321,17 +331,42 @@ fn is_saved_window_position_valid(app: &AppHandle, settings: &TranslationWindowS
     return false;
 }
 
-fn get_primary_monitor_work_area() -> RECT {
+#[cfg(windows)]
+fn get_primary_monitor_work_area(_app: &AppHandle) -> WorkArea {
     let rect: RECT = RECT::default();
     unsafe {
         windows::Win32::UI::WindowsAndMessaging::SystemParametersInfoA(
             SPI_GETWORKAREA,
             0,
-            Some(std::ptr::addr_of!(rect) as *mut c_void),
+            Some(std::ptr::addr_of!(rect) as *mut std::ffi::c_void),
             SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
         )
         .unwrap();
     };
 
-    rect
+    WorkArea {
+        right: rect.right,
+        bottom: rect.bottom,
+    }
+}
+
+// Work area without panels isn't available on other platforms, so the whole monitor is used
+#[cfg(not(windows))]
+fn get_primary_monitor_work_area(app: &AppHandle) -> WorkArea {
+    // Primary monitor is unknown in headless sessions and on some Wayland compositors
+    let Some(primary_monitor) = app.primary_monitor().ok().flatten() else {
+        log::warn!("Unable to get primary monitor, default work area is used.");
+        return WorkArea {
+            right: DEFAULT_WORK_AREA_WIDTH,
+            bottom: DEFAULT_WORK_AREA_HEIGHT,
+        };
+    };
+
+    let position = primary_monitor.position();
+    let size = primary_monitor.size();
+
+    WorkArea {
+        right: position.x + size.width as i32,
+        bottom: position.y + size.height as i32,
+    }
 }
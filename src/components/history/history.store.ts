@@ -49,6 +49,17 @@ export const useHistoryStore = defineStore('history', {
         queryRecordsInternal(this, true);
         updateTranslationIfNecessary(id);
       });
+
+      // Search might be requested before the window is loaded, e.g. by a deep link
+      await hostApi.translation.onSearchHistory(() => this.applyPendingSearch());
+      await this.applyPendingSearch();
+    },
+    async applyPendingSearch(): Promise<void> {
+      const search = await hostApi.translation.takePendingHistorySearch();
+      if (search) {
+        this.filter.word = search;
+        this.pageNumber = 1;
+      }
     },
     async queryRecords(): Promise<void> {
       try {
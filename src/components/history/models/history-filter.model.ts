@@ -3,6 +3,8 @@ export interface HistoryFilter {
   includeArchived: boolean;
   word?: string;
   translation?: string;
+  // Matches process name, window title or URL of the source application
+  application?: string;
   minTranslatedTime?: number;
   maxTranslatedTime?: number;
   tags?: string[];
@@ -2,10 +2,13 @@ import type {
   TranslateResult,
   TranslateDescriptor
 } from '~/components/translation/models/translation.model';
+import type { SourceContext } from '~/host/host-api.service';
 
 export interface TranslationInstance {
   readonly translationDate: number;
   readonly tags: ReadonlyArray<string>;
+  // Application the text has been selected in, missing for translations not made from selection
+  readonly source?: SourceContext;
 }
 
 export interface HistoryRecord extends TranslateDescriptor {
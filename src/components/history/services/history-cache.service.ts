@@ -7,6 +7,7 @@ import {
   authService,
   type AuthService
 } from '~/components/history/history-auth/services/auth.service';
+import type { SourceContext } from '~/host/host-api.service';
 import { HistoryColumnName } from '~/host/models/settings.model';
 import { ensureEndOfDate, ensureStartOfDate } from '~/utils/date.utils';
 import { sortBy, SortByGetter } from '~/utils/collection.utils';
@@ -167,6 +168,12 @@ export class HistoryCache {
           ?.toLowerCase()
           .includes(filter.translation.toLowerCase());
 
+      const applicationCondition =
+        !filter.application ||
+        (record.instances ?? []).some(instance =>
+          this.isSourceMatching(instance.source, filter.application!)
+        );
+
       const minTranslationsCondition =
         !filter.minTranslatedTime || record.translationsNumber >= filter.minTranslatedTime;
       const maxTranslationsCondition =
@@ -197,6 +204,7 @@ export class HistoryCache {
         archivedCondition &&
         wordCondition &&
         translationCondition &&
+        applicationCondition &&
         minTranslationsCondition &&
         maxTranslationsCondition &&
         tagsCondition &&
@@ -208,6 +216,13 @@ export class HistoryCache {
     });
   }
 
+  private isSourceMatching(source: SourceContext | undefined, application: string): boolean {
+    const search = application.toLowerCase();
+    return [source?.processName, source?.windowTitle, source?.url].some(value =>
+      value?.toLowerCase().includes(search)
+    );
+  }
+
   private ensureInitialized(): Promise<IDBPDatabase<HistoryDB>> {
     if (!this.db) {
       this.db = openDB<HistoryDB>(HISTORY_DB_NAME, 1, {
@@ -13,7 +13,7 @@ import {
   type AuthService
 } from '~/components/history/history-auth/services/auth.service';
 import { HistoryColumnName, Tag } from '~/host/models/settings.model';
-import { hostApi } from '~/host/host-api.service';
+import { hostApi, type SourceContext } from '~/host/host-api.service';
 import { uniq } from '~/utils/collection.utils';
 import { cloneDeep } from '~/utils/object.utils';
 
@@ -54,7 +54,8 @@ export class HistoryService {
   public async createHistoryRecord(
     id: string,
     descriptor: TranslateDescriptor,
-    translateResult: TranslateResult
+    translateResult: TranslateResult,
+    sourceContext?: SourceContext
   ): Promise<HistoryRecord> {
     const currentTime = new Date().getTime();
     const historyRecord = {
@@ -76,7 +77,8 @@ export class HistoryService {
       instances: [
         {
           translationDate: currentTime,
-          tags: this.getActiveCurrentTags()
+          tags: this.getActiveCurrentTags(),
+          source: sourceContext
         }
       ]
     };
@@ -92,7 +94,8 @@ export class HistoryService {
     record: HistoryRecord,
     translateResult: TranslateResult,
     incrementTranslationsNumber: boolean,
-    addTags: boolean
+    addTags: boolean,
+    sourceContext?: SourceContext
   ): Promise<HistoryRecord> {
     const currentTime = new Date().getTime();
 
@@ -100,7 +103,8 @@ export class HistoryService {
     if (incrementTranslationsNumber) {
       instances.push({
         translationDate: currentTime,
-        tags: this.getActiveCurrentTags()
+        tags: this.getActiveCurrentTags(),
+        source: sourceContext
       });
     }
 
@@ -19,11 +19,12 @@ export interface TranslateResultSentence {
   readonly origin: string | null;
   readonly suggestion: string | null | undefined;
   readonly languageSuggestion: string | null;
+  readonly detectedLanguage?: string | null;
   readonly similarWords?: ReadonlyArray<string>;
 }
 
 export interface TranslateResultCategory {
-  readonly partOfSpeech: string;
+  readonly partOfSpeech?: string;
   readonly baseForm: string;
   readonly entries: ReadonlyArray<TranslateResultCategoryEntry>;
 }
@@ -35,13 +36,13 @@ export interface TranslateResultCategoryEntry {
 }
 
 export interface TranslateResultDefinitionCategory {
-  readonly partOfSpeech: string;
+  readonly partOfSpeech?: string;
   readonly baseForm: string;
   readonly entries: ReadonlyArray<TranslateResultDefinitionCategoryEntry>;
 }
 
 export interface TranslateResultDefinitionCategoryEntry {
   readonly definition: string;
-  readonly sample: string;
+  readonly sample: string | null;
   readonly synonyms: ReadonlyArray<string>;
 }
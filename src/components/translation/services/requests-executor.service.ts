@@ -22,6 +22,7 @@ export class RequestsExecutor {
     }
 
     const response = await hostApi.translation.executeGoogleTranslateRequest(
+      crypto.randomUUID(),
       url,
       `f.req=${formData}`
     );
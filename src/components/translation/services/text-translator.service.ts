@@ -11,12 +11,7 @@ import type {
   TranslateDescriptor
 } from '~/components/translation/models/translation.model';
 import { logger, Logger } from '~/services/logger.service';
-
-import {
-  translationResponseParser,
-  TranslationResponseParser
-} from './translation-response-parser.service';
-import { requestsExecutor, RequestsExecutor } from './requests-executor.service';
+import { hostApi, type SourceContext } from '~/host/host-api.service';
 
 export type TranslationResponse = {
   result?: TranslateResult | undefined;
@@ -26,15 +21,15 @@ export type TranslationResponse = {
 export class TextTranslator {
   public constructor(
     private readonly logger: Logger,
-    private readonly responseParser: TranslationResponseParser,
     private readonly historyService: HistoryService,
-    private readonly authService: AuthService,
-    private readonly requestsExecutor: RequestsExecutor
+    private readonly authService: AuthService
   ) {}
 
   public async translate(
     request: TranslateRequest,
-    skipStatistics: boolean
+    skipStatistics: boolean,
+    requestId: string,
+    sourceContext?: SourceContext
   ): Promise<TranslationResponse> {
     this.logger.info(`[Translator]: Translating: "${getLogKey(request)}".`);
 
@@ -46,25 +41,31 @@ export class TextTranslator {
 
     const account = await this.authService.getAccount();
     if (!account) {
-      return { result: await this.getResponseFromService(request) };
+      return { result: await this.getResponseFromService(request, requestId) };
     }
 
     const id = await this.generateId(request);
 
     const [historyRecord, translateResult] = await Promise.all([
       this.getHistoryRecord(id),
-      this.getResponseFromService(request)
+      this.getResponseFromService(request, requestId)
     ]);
 
     let updatedRecord: HistoryRecord;
     if (!historyRecord) {
-      updatedRecord = await this.historyService.createHistoryRecord(id, request, translateResult);
+      updatedRecord = await this.historyService.createHistoryRecord(
+        id,
+        request,
+        translateResult,
+        sourceContext
+      );
     } else {
       updatedRecord = await this.historyService.updateHistoryRecord(
         historyRecord,
         translateResult,
         !skipStatistics,
-        !skipStatistics
+        !skipStatistics,
+        sourceContext
       );
     }
 
@@ -74,13 +75,14 @@ export class TextTranslator {
     };
   }
 
-  private async getResponseFromService(descriptor: TranslateDescriptor): Promise<TranslateResult> {
+  private async getResponseFromService(
+    descriptor: TranslateDescriptor,
+    requestId: string
+  ): Promise<TranslateResult> {
     const logKey = getLogKey(descriptor);
-    const response = await traceTimings(this.logger, `[Translator]: Translating ${logKey}`, () =>
-      this.getTranslationResponse(descriptor)
+    return traceTimings(this.logger, `[Translator]: Translating ${logKey}`, () =>
+      hostApi.translation.translate(requestId, descriptor)
     );
-
-    return this.responseParser.parse(response, descriptor.sentence);
   }
 
   private async getHistoryRecord(id: string): Promise<HistoryRecord | undefined> {
@@ -93,20 +95,6 @@ export class TextTranslator {
     return (sentence || '').trim();
   }
 
-  private async getTranslationResponse(descriptor: TranslateDescriptor): Promise<unknown> {
-    // Double-escape string for correct processing by rpc
-    const escapedData = this.escapeString(this.escapeString(descriptor.sentence));
-    const normalizedData = escapedData.trim().replace(/\r?\n/g, '\\\\n');
-    const data = `[[\\"${normalizedData}\\",\\"${descriptor.sourceLanguage}\\",\\"${
-      descriptor.targetLanguage
-    }\\",${descriptor.isForcedTranslation ? 'null' : '1'}],[]]`;
-    return this.requestsExecutor.executeGoogleTranslateRequest('MkEWBc', data);
-  }
-
-  private escapeString(str: string): string {
-    return str.replace(/\\/g, '\\\\').replace(/"/g, '\\"');
-  }
-
   private async generateId(descriptor: TranslateDescriptor): Promise<string> {
     const hash = await this.getHash(descriptor.sentence);
     return `${hash}${descriptor.isForcedTranslation ? '-forced' : ''}-${
@@ -127,10 +115,4 @@ export class TextTranslator {
   }
 }
 
-export const textTranslator = new TextTranslator(
-  logger,
-  translationResponseParser,
-  historyService,
-  authService,
-  requestsExecutor
-);
+export const textTranslator = new TextTranslator(logger, historyService, authService);
@@ -9,7 +9,7 @@ import { searchExecutor } from '../services/search-executor.service';
 import { textPlayer } from '../services/text-player.service';
 import { useGlobalErrorsStore } from '~/components/global-errors/global-errors.store';
 import { historyService } from '~/components/history/services/history.service';
-import { hostApi } from '~/host/host-api.service';
+import { hostApi, type SourceContext } from '~/host/host-api.service';
 import { Tag } from '~/host/models/settings.model';
 
 export interface TranslateResultState {
@@ -17,6 +17,7 @@ export interface TranslateResultState {
   translateResult?: TranslateResult;
   historyRecord?: HistoryRecord;
   isTranslationInProgress: boolean;
+  currentRequestId?: string;
   defaultTranslateResultView: TranslateResultViews;
 }
 
@@ -27,6 +28,7 @@ export const useTranslateResultStore = defineStore('translate-result', {
       translateResult: undefined,
       historyRecord: undefined,
       isTranslationInProgress: false,
+      currentRequestId: undefined,
       defaultTranslateResultView: TranslateResultViews.Translation
     };
     return state;
@@ -42,15 +44,33 @@ export const useTranslateResultStore = defineStore('translate-result', {
     async translateText(
       request: TranslateRequest,
       showDefinitions = false,
-      skipStatistics = false
+      skipStatistics = false,
+      sourceContext?: SourceContext
     ) {
       this.clearCurrentTranslation();
 
+      if (this.currentRequestId) {
+        hostApi.translation.cancelRequest(this.currentRequestId);
+      }
+
+      const requestId = crypto.randomUUID();
+      this.currentRequestId = requestId;
       this.isTranslationInProgress = true;
       this.translateDescriptor = getDescriptor(request);
 
       try {
-        const translateResponse = await textTranslator.translate(request, skipStatistics);
+        const translateResponse = await textTranslator.translate(
+          request,
+          skipStatistics,
+          requestId,
+          sourceContext
+        );
+
+        // Newer translation has been started while this one was in progress
+        if (this.currentRequestId !== requestId) {
+          return;
+        }
+
         this.translateResult = translateResponse.result;
         this.historyRecord = translateResponse.historyRecord;
         this.defaultTranslateResultView = showDefinitions
@@ -61,9 +81,16 @@ export const useTranslateResultStore = defineStore('translate-result', {
           hostApi.translation.emitHistoryRecordChangeEvent(this.historyRecord.id);
         }
       } catch (e: unknown) {
-        useGlobalErrorsStore().addError('Unable to translate text.', e);
+        const isRequestSuperseded =
+          this.currentRequestId !== requestId || hostApi.translation.isCancelledRequestError(e);
+        if (!isRequestSuperseded) {
+          useGlobalErrorsStore().addError('Unable to translate text.', e);
+        }
       } finally {
-        this.isTranslationInProgress = false;
+        if (this.currentRequestId === requestId) {
+          this.currentRequestId = undefined;
+          this.isTranslationInProgress = false;
+        }
       }
     },
 
@@ -2,7 +2,7 @@ import { defineStore } from 'pinia';
 
 import { useAppStore } from '~/app.store';
 import { useGlobalErrorsStore } from '~/components/global-errors/global-errors.store';
-import { hostApi } from '~/host/host-api.service';
+import { hostApi, PlayTextCommand, TranslateTextCommand } from '~/host/host-api.service';
 import { logger } from '~/services/logger.service';
 
 import type { TranslateRequest } from './models/requests.model';
@@ -34,13 +34,13 @@ export const useTranslationStore = defineStore('translation', {
             return;
           }
 
-          if (command === 'Play') {
-            this.handlePlayTextCommand();
+          if (command === 'ShowInput') {
+            this.handleShowInputCommand();
             return;
           }
 
-          if (command === 'ShowInput') {
-            this.handleShowInputCommand();
+          if ('Play' in command) {
+            this.handlePlayTextCommand(command.Play);
             return;
           }
 
@@ -50,10 +50,8 @@ export const useTranslationStore = defineStore('translation', {
           logger.error(error, 'Error getting last translation command.');
         });
 
-      hostApi.translation.onTranslateText(showDefinitions =>
-        this.handleTranslateTextCommand(showDefinitions)
-      );
-      hostApi.translation.onPlayText(() => this.handlePlayTextCommand());
+      hostApi.translation.onTranslateText(command => this.handleTranslateTextCommand(command));
+      hostApi.translation.onPlayText(command => this.handlePlayTextCommand(command));
       hostApi.translation.onShowInput(() => this.handleShowInputCommand());
 
       hostApi.view.onBeforeShow(() => {
@@ -65,9 +63,9 @@ export const useTranslationStore = defineStore('translation', {
         this.showInput = false;
       });
     },
-    async handlePlayTextCommand() {
+    async handlePlayTextCommand(command: PlayTextCommand) {
       try {
-        const text = await hostApi.translation.getTextFromClipboard();
+        const text = command.text ?? (await hostApi.translation.getTextFromClipboard());
         await textPlayer.playText({ text: text });
       } catch (error: unknown) {
         logger.error(error, 'Error playing text.');
@@ -91,13 +89,13 @@ export const useTranslationStore = defineStore('translation', {
       this.nonTextTranslation = false;
       this.showInput = true;
     },
-    async handleTranslateTextCommand(showDefinitions: boolean) {
+    async handleTranslateTextCommand(command: TranslateTextCommand) {
       const translateResult = useTranslateResultStore();
       const globalErrorsStore = useGlobalErrorsStore();
       const app = useAppStore();
 
       globalErrorsStore.clearErrors();
-      const text = await hostApi.translation.getTextFromClipboard();
+      const text = command.text ?? (await hostApi.translation.getTextFromClipboard());
 
       if (!text) {
         translateResult.clearCurrentTranslation();
@@ -112,10 +110,12 @@ export const useTranslationStore = defineStore('translation', {
         {
           sentence: text,
           isForcedTranslation: false,
-          sourceLanguage: app.settings.translation.sourceLanguage,
-          targetLanguage: app.settings.translation.targetLanguage
+          sourceLanguage: command.source_language ?? app.settings.translation.sourceLanguage,
+          targetLanguage: command.target_language ?? app.settings.translation.targetLanguage
         },
-        showDefinitions
+        command.show_definition,
+        false,
+        command.source_context ?? undefined
       );
     }
   }
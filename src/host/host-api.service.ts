@@ -12,13 +12,48 @@ import {
 } from '@tauri-apps/plugin-autostart';
 import { getCurrentWebview } from '@tauri-apps/api/webview';
 
+import type {
+  TranslateDescriptor,
+  TranslateResult
+} from '~/components/translation/models/translation.model';
+
 import { PartialSettings, Settings } from './models/settings.model';
 import { ViewNames } from './models/views.model';
 
 const HISTORY_RECORD_CHANGE_EVENT = 'history_record_changed';
 const SHOW_ON_LOAD_QUERY_PARAM = 'show_on_load';
+const CANCELLED_REQUEST_ERROR_KIND = 'cancelled';
+
+// Error returned by request commands, kind matches RequestError::kind in the backend
+export type RequestCommandError = {
+  kind: string;
+  message: string;
+};
+
+// Application the text has been selected in
+export type SourceContext = {
+  processName: string | null;
+  windowTitle: string | null;
+  url: string | null;
+};
 
-export type TranslationCommand = 'Play' | 'ShowInput' | { Translate: boolean };
+// Text is taken from clipboard when it's not provided in the command
+export type TranslateTextCommand = {
+  show_definition: boolean;
+  text: string | null;
+  source_language: string | null;
+  target_language: string | null;
+  source_context: SourceContext | null;
+};
+
+export type PlayTextCommand = {
+  text: string | null;
+};
+
+export type TranslationCommand =
+  | { Play: PlayTextCommand }
+  | 'ShowInput'
+  | { Translate: TranslateTextCommand };
 
 export const hostApi = {
   view: {
@@ -105,14 +140,14 @@ export const hostApi = {
   },
 
   translation: {
-    async onTranslateText(callback: (showDefinitions: boolean) => void): Promise<void> {
-      await listen('translate_text', (event: Event<{ show_definition: boolean }>) =>
-        callback(event.payload.show_definition)
+    async onTranslateText(callback: (command: TranslateTextCommand) => void): Promise<void> {
+      await listen('translate_text', (event: Event<TranslateTextCommand>) =>
+        callback(event.payload)
       );
     },
 
-    async onPlayText(callback: () => void): Promise<void> {
-      await listen('play_text', () => callback());
+    async onPlayText(callback: (command: PlayTextCommand) => void): Promise<void> {
+      await listen('play_text', (event: Event<PlayTextCommand>) => callback(event.payload));
     },
 
     async onShowInput(callback: () => void): Promise<void> {
@@ -127,14 +162,31 @@ export const hostApi = {
       return invoke<TranslationCommand>('last_translation_command');
     },
 
-    async executeGoogleTranslateRequest(url: string, body: string): Promise<string> {
+    async executeGoogleTranslateRequest(
+      requestId: string,
+      url: string,
+      body: string
+    ): Promise<string> {
       return invoke<string>('execute_google_translate_request', {
+        requestId,
         url,
         body,
         userAgent: navigator.userAgent
       });
     },
 
+    async translate(requestId: string, request: TranslateDescriptor): Promise<TranslateResult> {
+      return invoke<TranslateResult>('translate_result', { requestId, request });
+    },
+
+    async cancelRequest(requestId: string): Promise<void> {
+      return invoke<void>('cancel_request', { requestId });
+    },
+
+    isCancelledRequestError(error: unknown): boolean {
+      return (error as RequestCommandError | null)?.kind === CANCELLED_REQUEST_ERROR_KIND;
+    },
+
     async setPlayingState(isPlaying: boolean): Promise<void> {
       if (isPlaying) {
         await emit('play_start');
@@ -151,6 +203,14 @@ export const hostApi = {
       await listen(HISTORY_RECORD_CHANGE_EVENT, (event: Event<{ recordId: string }>) =>
         callback(event.payload.recordId)
       );
+    },
+
+    async onSearchHistory(callback: () => void): Promise<void> {
+      await listen('search_history', () => callback());
+    },
+
+    async takePendingHistorySearch(): Promise<string | null> {
+      return invoke<string | null>('take_pending_history_search');
     }
   },
 
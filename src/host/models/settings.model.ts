@@ -12,24 +12,99 @@ export type Settings = {
   scaling: ScalingSettings;
   hotkeys: HotkeySettings;
   supabase: SupabaseSettings;
+  apiServer: ApiServerSettings;
+  clipboardWatcher: ClipboardWatcherSettings;
 };
 
 export type PartialSettings = DeepPartial<Settings>;
 
 export type CoreSettings = {
+  extractionStrategies: ExtractionStrategyKind[];
   copyDelayMilliseconds: number;
+  preserveClipboard: boolean;
   requestTimeoutMilliseconds: number;
+  requestRetries: number;
+  requestRetryDelayMilliseconds: number;
+  circuitBreakerCooldownSeconds: number;
   logRequests: boolean;
+  requestsMockMode: RequestsMockMode;
   playVolume: number;
   searchPattern: string;
   lastRecordsToScanForMerge: number;
   levenshteinDistanceForMerge: number;
+  proxy: ProxySettings;
+  translationCacheMaxEntries: number;
+  translationCacheTtlHours: number;
+  textNormalization: TextNormalizationSettings;
+  ocr: OcrSettings;
+};
+
+export type RequestsMockMode = 'off' | 'record' | 'replay';
+
+export type ProxySettings = {
+  mode: ProxyMode;
+  scheme: ProxyScheme;
+  host: string;
+  port: number;
+  username: string | null;
+  password: string | null;
+  bypass: string[];
+};
+
+export type ProxyMode = 'none' | 'system' | 'manual';
+
+export type ProxyScheme = 'http' | 'https' | 'socks5';
+
+export type TextNormalizationSettings = {
+  joinHyphenatedWords: boolean;
+  joinWrappedLines: boolean;
+  stripListMarkers: boolean;
+  normalizeCharacters: boolean;
+  splitIdentifiers: boolean;
+  maxLength: number;
+};
+
+export type ExtractionStrategyKind =
+  | 'syntheticCopy'
+  | 'primarySelection'
+  | 'uiAutomation'
+  | 'clipboard';
+
+export type OcrSettings = {
+  enabled: boolean;
+  tesseractPath: string;
 };
 
 export type TranslationSettings = {
   sourceLanguage: string;
   targetLanguage: string;
   tags: Tag[];
+  provider: TranslationProviderSettings;
+};
+
+export type TranslationProviderKind = 'google' | 'deepl' | 'libreTranslate' | 'openAi';
+
+export type TranslationProviderSettings = {
+  kind: TranslationProviderKind;
+  deepl: DeepLProviderSettings;
+  libreTranslate: LibreTranslateProviderSettings;
+  openAi: OpenAiProviderSettings;
+};
+
+export type DeepLProviderSettings = {
+  url: string;
+  apiKey: string;
+};
+
+export type LibreTranslateProviderSettings = {
+  url: string;
+  apiKey: string | null;
+};
+
+export type OpenAiProviderSettings = {
+  url: string;
+  apiKey: string | null;
+  model: string;
 };
 
 export type Tag = {
@@ -94,8 +169,11 @@ export type HotkeySettings = {
   translate: Keys[];
   playText: Keys[];
   showDefinition: Keys[];
+  translateAndReplace: Keys[];
+  copyTranslation: Keys[];
   inputText: Keys[];
   toggleSuspend: Keys[];
+  toggleClipboardWatch: Keys[];
   zoomIn: Keys[];
   zoomOut: Keys[];
   resetZoom: Keys[];
@@ -109,3 +187,16 @@ export type SupabaseSettings = {
   projectUrl: string;
   anonKey: string;
 };
+
+export type ApiServerSettings = {
+  enabled: boolean;
+  port: number;
+  authToken: string;
+};
+
+export type ClipboardWatcherSettings = {
+  minLength: number;
+  maxLength: number;
+  ignoreUrls: boolean;
+  debounceMilliseconds: number;
+};
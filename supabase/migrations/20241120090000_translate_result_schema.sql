@@ -0,0 +1,9 @@
+-- Translate result is produced by the Rust backend with a versioned schema
+-- Existing records are not validated, since they were produced by older frontend parser
+ALTER TABLE ONLY "public"."history"
+    ADD CONSTRAINT "history_translate_result_schema" CHECK (
+        "jsonb_typeof"("translate_result" -> 'sentence') = 'object'
+        AND "jsonb_typeof"("translate_result" -> 'categories') = 'array'
+        AND "jsonb_typeof"("translate_result" -> 'definitions') = 'array'
+        AND "jsonb_typeof"("translate_result" -> 'version') = 'string'
+    ) NOT VALID;